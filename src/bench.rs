@@ -0,0 +1,188 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::ParseError, solution::Day};
+
+/// Summary of the timings of one phase, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub mean: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<u64> =
+            samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+
+        let n = nanos.len().max(1) as u64;
+        let median = match nanos.len() {
+            0 => 0,
+            len if len % 2 == 0 => (nanos[len / 2 - 1] + nanos[len / 2]) / 2,
+            len => nanos[len / 2],
+        };
+
+        Stats {
+            min: nanos.first().copied().unwrap_or(0),
+            median,
+            mean: nanos.iter().sum::<u64>() / n,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayBench {
+    pub fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+/// The benchmark results of several days, as written to and read from JSON.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub days: Vec<DayBench>,
+}
+
+/// Times parsing and both parts of `day` separately, `runs` times each.
+pub fn bench(
+    day: &Day,
+    input: &str,
+    runs: usize,
+) -> Result<DayBench, ParseError> {
+    let mut samples = [vec![], vec![], vec![]];
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = day.parse(black_box(input))?;
+        samples[0].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(day.answer(parsed.as_ref(), 1)).ok();
+        samples[1].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(day.answer(parsed.as_ref(), 2)).ok();
+        samples[2].push(start.elapsed());
+    }
+
+    let [parse, part1, part2] = samples.map(|s| Stats::from_samples(&s));
+
+    Ok(DayBench {
+        day: day.number,
+        runs,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+/// A phase whose median got slower than the baseline by more than the
+/// threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub phase: &'static str,
+    pub baseline: u64,
+    pub current: u64,
+}
+
+impl Regression {
+    /// The slowdown in percent.
+    pub fn change(&self) -> f64 {
+        percent_change(self.baseline, self.current)
+    }
+}
+
+pub fn percent_change(baseline: u64, current: u64) -> f64 {
+    (current as f64 - baseline as f64) / baseline.max(1) as f64 * 100.0
+}
+
+/// Compares the medians of `report` against `baseline`. Days or phases that
+/// are missing in the baseline are skipped.
+pub fn compare(
+    report: &Report,
+    baseline: &Report,
+    threshold_percent: f64,
+) -> Vec<Regression> {
+    let mut regressions = vec![];
+
+    for current in &report.days {
+        let Some(old) = baseline.days.iter().find(|d| d.day == current.day)
+        else {
+            continue;
+        };
+
+        for ((phase, new), (_, old)) in
+            current.phases().iter().zip(old.phases())
+        {
+            if percent_change(old.median, new.median) > threshold_percent {
+                regressions.push(Regression {
+                    day: current.day,
+                    phase,
+                    baseline: old.median,
+                    current: new.median,
+                });
+            }
+        }
+    }
+
+    regressions
+}
+
+#[test]
+fn test_bench() {
+    let ms = Duration::from_millis;
+    let stats = Stats::from_samples(&[ms(3), ms(1), ms(2), ms(10)]);
+    assert_eq!(stats.min, 1_000_000);
+    assert_eq!(stats.median, 2_500_000);
+    assert_eq!(stats.mean, 4_000_000);
+
+    let day = |day, median| DayBench {
+        day,
+        runs: 1,
+        parse: Stats {
+            min: 0,
+            median: 100,
+            mean: 0,
+        },
+        part1: Stats {
+            min: 0,
+            median,
+            mean: 0,
+        },
+        part2: Stats {
+            min: 0,
+            median: 100,
+            mean: 0,
+        },
+    };
+    let baseline = Report {
+        days: vec![day(1, 100), day(2, 100)],
+    };
+    let report = Report {
+        days: vec![day(1, 105), day(2, 150), day(3, 1000)],
+    };
+
+    let regressions = compare(&report, &baseline, 10.0);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!((regressions[0].day, regressions[0].phase), (2, "part1"));
+    assert_eq!(regressions[0].change(), 50.0);
+}
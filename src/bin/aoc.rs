@@ -0,0 +1,469 @@
+use aoc_2022::{
+    bench::{self, Report},
+    days::{self, DAYS},
+    read_input,
+    solution::{Day, Part},
+    verify::{self, Answers, Status},
+    visual::{self, Recorder},
+    InputSource,
+};
+use std::{env, fs, path::PathBuf, process, time::Duration};
+
+const USAGE: &str = "\
+usage: aoc run <day|all> [--part <1|2>] [--input <file|->]
+       aoc bench <day|all> [--runs <n>] [--input <file|->]
+                 [--output <file.json>] [--baseline <file.json>]
+                 [--threshold <percent>]
+       aoc verify [--answers <file.toml>]
+       aoc visualise <day> --output <file.gif|file.cast|dir> [--part <1|2>]
+                     [--input <file|->] [--skip <n>] [--scale <n>]
+       aoc report <day> <name> [--input <file|->] [--output <file>]
+
+The input defaults to $AOC_INPUT_DIR/inputNN.txt (or input/inputNN.txt).
+Use `--input -` to read it from stdin.
+
+`bench` times parsing and both parts separately. With --baseline, medians
+that are slower than the baseline by more than --threshold percent (default
+10) are reported as regressions and the exit code is 1.
+
+`verify` runs every day and compares the results with answers.toml. Days
+without an input file are listed as `no input`.
+
+`visualise` records the progress of a part as an animated GIF, an asciicast
+or a directory of PPM images, depending on --output. Only every (n+1)-th
+frame is kept with --skip, and --scale sets the pixels per cell (default 4).
+
+`report` prints extra output of a day, e.g. `aoc report 21 dot` for the
+monkey graph in Graphviz format, or writes it to --output.";
+
+#[derive(PartialEq)]
+enum Command {
+    Run,
+    Bench,
+    Verify,
+    Visualise,
+    Report,
+}
+
+struct Args {
+    command: Command,
+    days: Vec<&'static Day>,
+    part: Part,
+    input: InputSource,
+    runs: usize,
+    output: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+    answers: PathBuf,
+    skip: usize,
+    scale: usize,
+    report: String,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut args = args.iter();
+
+    let command = match args.next().map(String::as_str) {
+        Some("run") => Command::Run,
+        Some("bench") => Command::Bench,
+        Some("verify") => Command::Verify,
+        Some("visualise") => Command::Visualise,
+        Some("report") => Command::Report,
+        Some(cmd) => return Err(format!("unknown command `{cmd}`")),
+        None => return Err("missing command".to_owned()),
+    };
+
+    let day = match command {
+        Command::Verify => Some("all"),
+        _ => args.next().map(String::as_str),
+    };
+
+    let days = match day {
+        Some("all") => DAYS.iter().collect(),
+        Some(day) => {
+            let number =
+                day.parse().map_err(|_| format!("invalid day `{day}`"))?;
+            let day = days::find(number).ok_or_else(|| {
+                format!("day {number} has no solution in this crate")
+            })?;
+            vec![day]
+        }
+        None => return Err("missing day".to_owned()),
+    };
+
+    let report = match command {
+        Command::Report => match args.next() {
+            Some(name) => name.clone(),
+            None => return Err("missing report name".to_owned()),
+        },
+        _ => String::new(),
+    };
+
+    let mut parsed = Args {
+        command,
+        days,
+        part: Part::Both,
+        input: InputSource::Default,
+        runs: 10,
+        output: None,
+        baseline: None,
+        threshold: 10.0,
+        answers: verify::answers_file(),
+        skip: 0,
+        scale: 4,
+        report,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("{arg} expects a value"))
+                .map(String::as_str)
+        };
+
+        match (&parsed.command, arg.as_str()) {
+            (Command::Run | Command::Visualise, "--part") => {
+                parsed.part = match value()? {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    _ => return Err("--part expects 1 or 2".to_owned()),
+                }
+            }
+            (
+                Command::Run
+                | Command::Bench
+                | Command::Visualise
+                | Command::Report,
+                "--input",
+            ) => parsed.input = InputSource::from_arg(value()?),
+            (Command::Bench, "--runs") => {
+                parsed.runs = match value()?.parse() {
+                    Ok(runs @ 1..) => runs,
+                    _ => return Err("--runs expects a positive number".into()),
+                }
+            }
+            (
+                Command::Bench | Command::Visualise | Command::Report,
+                "--output",
+            ) => parsed.output = Some(PathBuf::from(value()?)),
+            (Command::Bench, "--baseline") => {
+                parsed.baseline = Some(PathBuf::from(value()?))
+            }
+            (Command::Bench, "--threshold") => {
+                parsed.threshold = value()?
+                    .parse()
+                    .map_err(|_| "--threshold expects a number".to_owned())?
+            }
+            (Command::Verify, "--answers") => {
+                parsed.answers = PathBuf::from(value()?)
+            }
+            (Command::Visualise, "--skip") => {
+                parsed.skip = value()?
+                    .parse()
+                    .map_err(|_| "--skip expects a number".to_owned())?
+            }
+            (Command::Visualise, "--scale") => {
+                parsed.scale = match value()?.parse() {
+                    Ok(scale @ 1..) => scale,
+                    _ => return Err("--scale expects a positive number".into()),
+                }
+            }
+            (_, arg) => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    if parsed.days.len() > 1 && parsed.input != InputSource::Default {
+        return Err("--input can only be used with a single day".to_owned());
+    }
+
+    if matches!(parsed.command, Command::Visualise | Command::Report)
+        && parsed.days.len() > 1
+    {
+        return Err("visualise and report need a single day".to_owned());
+    }
+
+    if parsed.command == Command::Visualise && parsed.output.is_none() {
+        return Err("visualise needs --output".to_owned());
+    }
+
+    Ok(parsed)
+}
+
+fn run(day: &Day, part: Part, input: &InputSource) -> Result<(), String> {
+    let input =
+        read_input(day.number, input).map_err(|e| format!("error: {e}"))?;
+
+    let parsed = day.parse(&input).map_err(|e| e.render(&input))?;
+
+    for number in 1..=2 {
+        if !part.includes(number) {
+            continue;
+        }
+
+        let answer = day.answer(parsed.as_ref(), number).map_err(|e| {
+            format!("error: day {}, part{number}: {e}", day.number)
+        })?;
+        println!("part{number} = {answer}");
+    }
+
+    Ok(())
+}
+
+fn format_nanos(nanos: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos))
+}
+
+fn run_bench(args: &Args) -> Result<bool, String> {
+    let baseline = match &args.baseline {
+        Some(path) => {
+            let json = fs::read_to_string(path).map_err(|e| {
+                format!("error: cannot read {}: {e}", path.display())
+            })?;
+            Some(serde_json::from_str::<Report>(&json).map_err(|e| {
+                format!("error: invalid baseline {}: {e}", path.display())
+            })?)
+        }
+        None => None,
+    };
+
+    let mut report = Report::default();
+
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  {:>8}",
+        "day", "phase", "min", "median", "mean", "change"
+    );
+
+    for day in &args.days {
+        let input = read_input(day.number, &args.input)
+            .map_err(|e| format!("error: {e}"))?;
+        let result = bench::bench(day, &input, args.runs)
+            .map_err(|e| e.render(&input))?;
+
+        let old = baseline
+            .as_ref()
+            .and_then(|b| b.days.iter().find(|d| d.day == day.number));
+
+        for (idx, (phase, stats)) in result.phases().into_iter().enumerate() {
+            let change = old.map_or(String::new(), |old| {
+                let old = old.phases()[idx].1.median;
+                format!("{:+.1}%", bench::percent_change(old, stats.median))
+            });
+
+            println!(
+                "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  {:>8}",
+                day.number,
+                phase,
+                format_nanos(stats.min),
+                format_nanos(stats.median),
+                format_nanos(stats.mean),
+                change
+            );
+        }
+
+        report.days.push(result);
+    }
+
+    if let Some(path) = &args.output {
+        let json = serde_json::to_string_pretty(&report).unwrap();
+        fs::write(path, json + "\n").map_err(|e| {
+            format!("error: cannot write {}: {e}", path.display())
+        })?;
+    }
+
+    let Some(baseline) = baseline else {
+        return Ok(true);
+    };
+
+    let regressions = bench::compare(&report, &baseline, args.threshold);
+
+    for r in &regressions {
+        println!(
+            "regression: day {} {} median {} -> {} ({:+.1}%)",
+            r.day,
+            r.phase,
+            format_nanos(r.baseline),
+            format_nanos(r.current),
+            r.change()
+        );
+    }
+
+    Ok(regressions.is_empty())
+}
+
+fn run_verify(args: &Args) -> Result<bool, String> {
+    let answers =
+        Answers::load(&args.answers).map_err(|e| format!("error: {e}"))?;
+    let mut failures = vec![];
+
+    println!("{:>3}  {:<8}  {:<8}", "day", "part1", "part2");
+
+    for number in 1..=25 {
+        let Some(day) = args.days.iter().find(|d| d.number == number) else {
+            println!("{number:>3}  {:<8}  {:<8}", "missing", "missing");
+            continue;
+        };
+
+        let input = match read_input(number, &InputSource::Default) {
+            Ok(input) => input,
+            Err(e) if e.is_not_found() => {
+                println!("{number:>3}  {:<8}  {:<8}", "no input", "no input");
+                continue;
+            }
+            Err(e) => return Err(format!("error: {e}")),
+        };
+        let status = verify::check(day, &input, &answers)
+            .map_err(|e| e.render(&input))?;
+
+        println!("{number:>3}  {:<8}  {:<8}", status[0], status[1]);
+
+        for (part, status) in status.into_iter().enumerate() {
+            if let Status::Fail { expected, actual } = status {
+                failures.push((number, part + 1, expected, actual));
+            }
+        }
+    }
+
+    for (day, part, expected, actual) in &failures {
+        println!("\nday {day} part{part}: expected {expected}, got {actual}");
+    }
+
+    Ok(failures.is_empty())
+}
+
+fn run_visualise(args: &Args) -> Result<bool, String> {
+    let day = args.days[0];
+    let path = args.output.as_ref().unwrap();
+    let part = if args.part == Part::Two { 2 } else { 1 };
+
+    let input = read_input(day.number, &args.input)
+        .map_err(|e| format!("error: {e}"))?;
+    let sink = visual::sink_for(path, args.scale)
+        .map_err(|e| format!("error: cannot create {}: {e}", path.display()))?;
+    let mut recorder = Recorder::new(sink, args.skip);
+
+    let parsed = day.parse(&input).map_err(|e| e.render(&input))?;
+
+    if !day
+        .visualise(parsed.as_ref(), part, &mut recorder)
+        .map_err(|e| format!("error: day {}, part{part}: {e}", day.number))?
+    {
+        return Err(format!("error: day {} has no visualisation", day.number));
+    }
+
+    let frames = recorder
+        .finish()
+        .map_err(|e| format!("error: cannot write {}: {e}", path.display()))?;
+    println!("wrote {frames} frames to {}", path.display());
+
+    Ok(true)
+}
+
+fn run_report(args: &Args) -> Result<bool, String> {
+    let day = args.days[0];
+    let input = read_input(day.number, &args.input)
+        .map_err(|e| format!("error: {e}"))?;
+
+    let parsed = day.parse(&input).map_err(|e| e.render(&input))?;
+
+    let report = match day.report(parsed.as_ref(), &args.report) {
+        Some(Ok(report)) => report,
+        Some(Err(e)) => {
+            return Err(format!(
+                "error: day {}, report `{}`: {e}",
+                day.number, args.report
+            ))
+        }
+        None => {
+            return Err(format!(
+                "error: day {} has no report `{}`",
+                day.number, args.report
+            ))
+        }
+    };
+
+    match &args.output {
+        Some(path) => fs::write(path, report).map_err(|e| {
+            format!("error: cannot write {}: {e}", path.display())
+        })?,
+        None => print!("{report}"),
+    }
+
+    Ok(true)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("error: {e}\n{USAGE}");
+        process::exit(2);
+    });
+
+    let check = match args.command {
+        Command::Bench => Some(run_bench as fn(&Args) -> _),
+        Command::Verify => Some(run_verify as fn(&Args) -> _),
+        Command::Visualise => Some(run_visualise as fn(&Args) -> _),
+        Command::Report => Some(run_report as fn(&Args) -> _),
+        Command::Run => None,
+    };
+
+    if let Some(check) = check {
+        match check(&args) {
+            Ok(true) => (),
+            Ok(false) => process::exit(1),
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+
+        return;
+    }
+
+    let multiple = args.days.len() > 1;
+
+    for day in &args.days {
+        if multiple {
+            println!("day {:02}", day.number);
+        }
+
+        if let Err(e) = run(day, args.part, &args.input) {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+#[test]
+fn test_parse_args() {
+    let parse = |args: &str| {
+        let args = args.split_whitespace().map(str::to_owned);
+        parse_args(&args.collect::<Vec<_>>())
+    };
+    let numbers =
+        |args: &Args| args.days.iter().map(|d| d.number).collect::<Vec<_>>();
+
+    let args = parse("verify --answers x.toml").unwrap();
+    assert!(args.command == Command::Verify);
+    assert_eq!(args.answers, PathBuf::from("x.toml"));
+    assert_eq!(args.days.len(), DAYS.len());
+    assert!(parse("verify 1").is_err());
+
+    let args = parse("run 1 --part 1").unwrap();
+    assert!(args.command == Command::Run);
+    assert_eq!((numbers(&args), args.part), (vec![1], Part::One));
+    assert_eq!(parse("run all").unwrap().days.len(), DAYS.len());
+
+    let args = parse("bench 1 --runs 2").unwrap();
+    assert!(args.command == Command::Bench);
+    assert_eq!((numbers(&args), args.runs), (vec![1], 2));
+    assert!(parse("bench 1 --runs 0").is_err());
+
+    let args = parse("report 21 dot --output graph.dot").unwrap();
+    assert_eq!((numbers(&args), args.report.as_str()), (vec![21], "dot"));
+    assert_eq!(args.output, Some(PathBuf::from("graph.dot")));
+    assert!(parse("report 21").is_err());
+
+    assert!(parse("run 1 --answers x.toml").is_err());
+    assert!(parse("run").is_err());
+    assert!(parse("fly 1").is_err());
+}
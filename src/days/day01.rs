@@ -0,0 +1,48 @@
+use std::marker::PhantomData;
+
+use crate::{
+    error::{parse_at, ParseError},
+    solution::{Solution, Solver},
+};
+
+fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    let groups = input
+        .split("\n\n")
+        .map(|g| g.lines().map(|l| parse_at(input, l)).collect())
+        .collect::<Result<Vec<Vec<u64>>, _>>()?;
+
+    let mut group_sums: Vec<u64> =
+        groups.iter().map(|g| g.iter().sum()).collect();
+    group_sums.sort();
+    Ok(group_sums)
+}
+
+fn part1(group_sums: &[u64]) -> u64 {
+    *group_sums.last().unwrap()
+}
+
+fn part2(group_sums: &[u64]) -> u64 {
+    group_sums.iter().rev().take(3).sum()
+}
+
+struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day01>;
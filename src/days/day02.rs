@@ -1,7 +1,11 @@
-use aoc_2022::input_file;
-use std::fs;
+use std::marker::PhantomData;
 
-#[derive(Clone, PartialEq)]
+use crate::{
+    error::ParseError,
+    solution::{Solution, Solver},
+};
+
+#[derive(Clone, Copy, PartialEq)]
 enum Play {
     Rock = 1,
     Paper = 2,
@@ -25,22 +29,23 @@ fn game(opponent: &Play, you: &Play) -> GameResult {
     }
 }
 
-fn parse_play(input: u8) -> Play {
-    match input {
-        b'A' | b'X' => Play::Rock,
-        b'B' | b'Y' => Play::Paper,
-        b'C' | b'Z' => Play::Scissors,
-        _ => unreachable!(),
+fn parse_play(input: &str, play: &str) -> Result<Play, ParseError> {
+    match play {
+        "A" | "X" => Ok(Play::Rock),
+        "B" | "Y" => Ok(Play::Paper),
+        "C" | "Z" => Ok(Play::Scissors),
+        _ => Err(ParseError::at(input, play, "unknown play")),
     }
 }
 
-fn parse(input: &str) -> Vec<(Play, Play)> {
+fn parse(input: &str) -> Result<Vec<(Play, Play)>, ParseError> {
     input
         .lines()
-        .map(|l| {
-            let words: Vec<_> =
-                l.split(' ').map(|s| parse_play(s.as_bytes()[0])).collect();
-            (words[0].clone(), words[1].clone())
+        .map(|l| match l.split(' ').collect::<Vec<_>>().as_slice() {
+            [opponent, you] => {
+                Ok((parse_play(input, opponent)?, parse_play(input, you)?))
+            }
+            _ => Err(ParseError::at(input, l, "expected two plays")),
         })
         .collect()
 }
@@ -77,20 +82,35 @@ fn part2(puzzle: &[(Play, Play)]) -> i64 {
     score
 }
 
-fn main() {
-    let input = fs::read_to_string(input_file("input02.txt")).unwrap();
-    let input = parse(&input);
-    println!("part1 = {}", part1(&input));
-    println!("part2 = {}", part2(&input));
+struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(Play, Play)>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(input))
+    }
 }
 
+pub const SOLVER: &dyn Solver = &PhantomData::<Day02>;
+
 #[test]
 fn test_day() {
     let input = "\
 A Y
 B X
 C Z";
-    let input = parse(input);
+    let input = parse(input).unwrap();
 
     assert_eq!(part1(&input), 15);
     assert_eq!(part2(&input), 12);
@@ -0,0 +1,123 @@
+use std::{collections::HashSet, marker::PhantomData};
+
+use crate::{
+    error::ParseError,
+    solution::{Solution, Solver},
+};
+
+fn check_items(input: &str, line: &str) -> Result<(), ParseError> {
+    match line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        Some((idx, c)) => Err(ParseError::at(
+            input,
+            &line[idx..idx + c.len_utf8()],
+            "invalid item",
+        )),
+        None => Ok(()),
+    }
+}
+
+type Rucksack = (HashSet<u8>, HashSet<u8>);
+
+fn parse_part1(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    let mut backpack = vec![];
+
+    for line in input.lines() {
+        check_items(input, line)?;
+
+        if line.len() % 2 != 0 {
+            return Err(ParseError::at(
+                input,
+                line,
+                "compartments differ in size",
+            ));
+        }
+
+        let (left, right) = line.split_at(line.len() / 2);
+
+        backpack.push((left.bytes().collect(), right.bytes().collect()))
+    }
+
+    Ok(backpack)
+}
+
+fn parse_part2(input: &str) -> Result<Vec<HashSet<u8>>, ParseError> {
+    input
+        .lines()
+        .map(|l| check_items(input, l).map(|_| l.bytes().collect()))
+        .collect()
+}
+
+fn priority(c: u8) -> u8 {
+    if c.is_ascii_lowercase() {
+        c - b'a' + 1
+    } else if c.is_ascii_uppercase() {
+        c - b'A' + 27
+    } else {
+        0
+    }
+}
+
+fn part1(puzzle: &[Rucksack]) -> i64 {
+    let mut total_priority = 0;
+
+    for (left, right) in puzzle {
+        for c in left.iter() {
+            if right.contains(c) {
+                total_priority += priority(*c) as i64;
+                break;
+            }
+        }
+    }
+
+    total_priority
+}
+
+fn part2(puzzle: &[HashSet<u8>]) -> i64 {
+    let mut total_priority = 0;
+
+    for group in puzzle.chunks(3) {
+        for c in &group[0] {
+            if group[1].contains(c) && group[2].contains(c) {
+                total_priority += priority(*c) as i64;
+                break;
+            }
+        }
+    }
+
+    total_priority
+}
+
+struct Day03;
+
+impl Solution for Day03 {
+    type Input = (Vec<Rucksack>, Vec<HashSet<u8>>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse_part1(input)?, parse_part2(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(&input.0))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(&input.1))
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day03>;
+
+#[test]
+fn test_day03() {
+    let input = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+    assert_eq!(part1(&parse_part1(input).unwrap()), 157);
+    assert_eq!(part2(&parse_part2(input).unwrap()), 70);
+}
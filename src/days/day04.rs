@@ -0,0 +1,90 @@
+use std::{marker::PhantomData, ops::RangeInclusive};
+
+use crate::{
+    error::{parse_at, ParseError},
+    solution::{Solution, Solver},
+};
+
+type Assignment = (RangeInclusive<i64>, RangeInclusive<i64>);
+
+fn parse(input: &str) -> Result<Vec<Assignment>, ParseError> {
+    let mut ranges = vec![];
+    for line in input.lines() {
+        if let [a1, a2, b1, b2] =
+            line.split(['-', ',']).collect::<Vec<_>>().as_slice()
+        {
+            ranges.push((
+                parse_at(input, a1)?..=parse_at(input, a2)?,
+                parse_at(input, b1)?..=parse_at(input, b2)?,
+            ));
+        } else {
+            return Err(ParseError::at(input, line, "expected two ranges"));
+        }
+    }
+
+    Ok(ranges)
+}
+
+fn fully_contains(
+    left: &RangeInclusive<i64>,
+    right: &RangeInclusive<i64>,
+) -> bool {
+    left.contains(right.start()) && left.contains(right.end())
+}
+
+fn overlaps(left: &RangeInclusive<i64>, right: &RangeInclusive<i64>) -> bool {
+    left.contains(right.start()) || left.contains(right.end())
+}
+
+fn part1(puzzle: &[Assignment]) -> usize {
+    puzzle
+        .iter()
+        .filter(|(left, right)| {
+            fully_contains(left, right) || fully_contains(right, left)
+        })
+        .count()
+}
+
+fn part2(puzzle: &[Assignment]) -> usize {
+    puzzle
+        .iter()
+        .filter(|(left, right)| overlaps(left, right) || overlaps(right, left))
+        .count()
+}
+
+struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Assignment>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day04>;
+
+#[test]
+fn test_day04() {
+    let input = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 2);
+    assert_eq!(part2(&input), 4);
+}
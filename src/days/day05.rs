@@ -0,0 +1,133 @@
+use std::{collections::VecDeque, marker::PhantomData};
+
+use crate::{
+    error::{parse_at, ParseError},
+    solution::{Solution, Solver},
+};
+
+#[derive(Debug, Clone)]
+struct Puzzle {
+    stacks: Vec<VecDeque<char>>,
+    moves: Vec<(usize, usize, usize)>,
+}
+
+fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let Some((drawing, procedure)) = input.split_once("\n\n") else {
+        return Err(ParseError::after(
+            input,
+            input,
+            "expected a blank line before the moves",
+        ));
+    };
+
+    let num_stacks =
+        drawing.lines().map(|l| l.len() / 4 + 1).max().unwrap_or(0);
+    let mut stacks = vec![VecDeque::new(); num_stacks];
+    let mut moves = vec![];
+
+    for line in drawing.lines() {
+        let mut crates = line.char_indices().peekable();
+
+        while let Some(&(i, c)) = crates.peek() {
+            if c == '[' {
+                crates.next();
+                match crates.next() {
+                    Some((_, c)) if c != ']' => stacks[i / 4].push_front(c),
+                    _ => {
+                        return Err(ParseError::at(
+                            input,
+                            &line[i..],
+                            "expected a crate",
+                        ))
+                    }
+                }
+                crates.next();
+                crates.next();
+            } else {
+                crates.next();
+            }
+        }
+    }
+
+    let stack = |word: &str| match parse_at::<usize>(input, word)? {
+        n @ 1.. if n <= num_stacks => Ok(n - 1),
+        _ => Err(ParseError::at(input, word, "no such stack")),
+    };
+
+    for line in procedure.lines() {
+        let words: Vec<_> = line.split(' ').collect();
+        if let ["move", count, "from", from, "to", to] = words.as_slice() {
+            moves.push((parse_at(input, count)?, stack(from)?, stack(to)?));
+        } else {
+            return Err(ParseError::at(input, line, "invalid move"));
+        }
+    }
+
+    Ok(Puzzle { stacks, moves })
+}
+
+fn part1(mut puzzle: Puzzle) -> String {
+    for &(count, from, to) in &puzzle.moves {
+        for _ in 0..count {
+            let elem = puzzle.stacks[from].pop_back().unwrap();
+            puzzle.stacks[to].push_back(elem);
+        }
+    }
+
+    puzzle.stacks.iter().map(|s| s.iter().last().unwrap()).collect()
+}
+
+fn part2(mut puzzle: Puzzle) -> String {
+    let mut tmp_stack = vec![];
+    for &(count, from, to) in &puzzle.moves {
+        for _ in 0..count {
+            let elem = puzzle.stacks[from].pop_back().unwrap();
+            tmp_stack.push(elem);
+        }
+
+        for _ in 0..count {
+            puzzle.stacks[to].push_back(tmp_stack.pop().unwrap());
+        }
+    }
+
+    puzzle.stacks.iter().map(|s| s.iter().last().unwrap()).collect()
+}
+
+struct Day05;
+
+impl Solution for Day05 {
+    type Input = Puzzle;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input.clone()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(input.clone()))
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day05>;
+
+#[test]
+fn test_day05() {
+    let input = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(input.clone()), "CMZ");
+    assert_eq!(part2(input), "MCD");
+}
@@ -0,0 +1,115 @@
+use std::marker::PhantomData;
+
+use crate::{
+    error::ParseError,
+    solution::{Solution, Solver},
+};
+
+/// The signal as letter indices, `a` being 0.
+fn parse(input: &str) -> Result<Vec<u8>, ParseError> {
+    let signal = input.trim_end();
+
+    signal
+        .char_indices()
+        .map(|(idx, c)| match c {
+            'a'..='z' => Ok(c as u8 - b'a'),
+            _ => Err(ParseError::at(
+                input,
+                &signal[idx..idx + c.len_utf8()],
+                "invalid character",
+            )),
+        })
+        .collect()
+}
+
+/// The number of characters read until the last `window` of them are all
+/// different. Keeps a count per letter for the current window, so every
+/// character costs O(1) regardless of the window size.
+fn marker(signal: &[u8], window: usize) -> Option<usize> {
+    let mut counts = [0usize; 26];
+    let mut distinct = 0;
+
+    if window == 0 {
+        return Some(0);
+    }
+
+    for (idx, &c) in signal.iter().enumerate() {
+        counts[c as usize] += 1;
+        if counts[c as usize] == 1 {
+            distinct += 1;
+        }
+
+        if let Some(old) = idx.checked_sub(window) {
+            let old = signal[old] as usize;
+            counts[old] -= 1;
+            if counts[old] == 0 {
+                distinct -= 1;
+            }
+        }
+
+        if distinct == window {
+            return Some(idx + 1);
+        }
+    }
+
+    None
+}
+
+fn part1(signal: &[u8]) -> Option<usize> {
+    marker(signal, 4)
+}
+
+fn part2(signal: &[u8]) -> Option<usize> {
+    marker(signal, 14)
+}
+
+struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        part1(input).ok_or_else(|| "no start-of-packet marker".to_owned())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        part2(input).ok_or_else(|| "no start-of-message marker".to_owned())
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day06>;
+
+#[test]
+fn test_day06() {
+    let examples = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    for (input, packet, message) in examples {
+        let input = parse(input).unwrap();
+        assert_eq!(part1(&input), Some(packet));
+        assert_eq!(part2(&input), Some(message));
+    }
+
+    assert_eq!(part1(&parse("abc\n").unwrap()), None);
+    assert_eq!(part2(&parse("\n\n").unwrap()), None);
+
+    let input = parse("aab").unwrap();
+    assert_eq!(marker(&input, 1), Some(1));
+    assert_eq!(marker(&input, 2), Some(3));
+    assert_eq!(marker(&input, 3), None);
+    assert_eq!(marker(&parse("abc").unwrap(), 27), None);
+
+    let err = parse("abC\n").unwrap_err();
+    assert_eq!((err.column, err.message.as_str()), (3, "invalid character"));
+}
@@ -1,14 +1,18 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, marker::PhantomData};
 
-fn parse(input: &str) -> Vec<(Vec<String>, usize)> {
+use crate::{
+    error::{parse_at, ParseError},
+    solution::{Solution, Solver},
+};
+
+fn parse(input: &str) -> Result<Vec<(Vec<String>, usize)>, ParseError> {
     let mut files = vec![];
     let mut lines = input.lines().peekable();
 
     let mut cwd = vec![];
 
-    while let Some(line) = lines.peek() {
+    while let Some(line) = lines.next() {
         let words = line.split_ascii_whitespace().collect::<Vec<_>>();
-        lines.next();
         match words.as_slice() {
             ["$", "cd", ".."] => {
                 cwd.pop();
@@ -26,20 +30,31 @@ fn parse(input: &str) -> Vec<(Vec<String>, usize)> {
                     let entry =
                         output.split_ascii_whitespace().collect::<Vec<_>>();
 
-                    if let (Ok(size), file) = (entry[0].parse(), entry[1]) {
-                        let mut entry = cwd.clone();
-                        entry.extend([file.to_owned()]);
-                        files.push((entry, size));
+                    match entry.as_slice() {
+                        ["dir", _] => (),
+                        [size, file] => {
+                            let size = parse_at(input, size)?;
+                            let mut entry = cwd.clone();
+                            entry.extend([file.to_string()]);
+                            files.push((entry, size));
+                        }
+                        _ => {
+                            return Err(ParseError::at(
+                                input,
+                                output,
+                                "invalid directory entry",
+                            ))
+                        }
                     }
 
                     lines.next();
                 }
             }
-            _ => unreachable!(),
+            _ => return Err(ParseError::at(input, line, "unknown command")),
         }
     }
 
-    files
+    Ok(files)
 }
 
 fn dir_sizes(files: &[(Vec<String>, usize)]) -> HashMap<&[String], usize> {
@@ -66,29 +81,40 @@ fn part1(files: &[(Vec<String>, usize)]) -> usize {
         .sum()
 }
 
-fn part2(files: &[(Vec<String>, usize)]) -> usize {
+fn part2(files: &[(Vec<String>, usize)]) -> Option<usize> {
     let mut sizes =
         dir_sizes(files).iter().map(|(_, &s)| s).collect::<Vec<_>>();
     let total_size: usize = files.iter().map(|(_, s)| s).sum();
 
     sizes.sort_unstable();
 
-    for size in sizes.iter() {
-        if total_size - size <= 40000000 {
-            return *size;
-        }
+    sizes.into_iter().find(|size| total_size - size <= 40000000)
+}
+
+struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<(Vec<String>, usize)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
     }
 
-    unreachable!()
-}
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input))
+    }
 
-fn main() {
-    let input = include_str!("../../input/input07.txt");
-    let input = parse(input);
-    println!("part1 = {}", part1(&input));
-    println!("part2 = {}", part2(&input));
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        part2(input).ok_or_else(|| {
+            "no directory frees enough space when deleted".to_owned()
+        })
+    }
 }
 
+pub const SOLVER: &dyn Solver = &PhantomData::<Day07>;
+
 #[test]
 fn test_day07() {
     let input = "\
@@ -115,8 +141,9 @@ $ ls
 8033020 d.log
 5626152 d.ext
 7214296 k";
-    let input = parse(input);
+    let input = parse(input).unwrap();
 
     assert_eq!(part1(&input), 95437);
-    assert_eq!(part2(&input), 24933642);
+    assert_eq!(part2(&input), Some(24933642));
+    assert_eq!(part2(&parse("").unwrap()), None);
 }
@@ -0,0 +1,98 @@
+use std::marker::PhantomData;
+
+use crate::{
+    error::ParseError,
+    grid::{Grid, GridView, Pos, NEIGHBOURS4},
+    solution::{Solution, Solver},
+};
+
+fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, |_, c| c.to_digit(10).ok_or("invalid tree height"))
+}
+
+/// The trees seen from `(x, y)` when looking in direction `(dx, dy)`, up to
+/// the edge of the grid.
+fn sight_line(
+    puzzle: &Grid<u32>,
+    (x, y): Pos,
+    (dx, dy): Pos,
+) -> impl Iterator<Item = u32> + '_ {
+    (1..).map_while(move |i| puzzle.get((x + i * dx, y + i * dy)).copied())
+}
+
+fn part1(puzzle: &Grid<u32>) -> usize {
+    let visible = |pos, height| {
+        NEIGHBOURS4
+            .iter()
+            .any(|&dir| sight_line(puzzle, pos, dir).all(|h| height > h))
+    };
+
+    puzzle
+        .iter()
+        .filter(|&(pos, &height)| visible(pos, height))
+        .count()
+}
+
+fn count_trees(height: u32, trees: impl Iterator<Item = u32>) -> usize {
+    let mut dist = 0;
+    for tree in trees {
+        dist += 1;
+
+        if height <= tree {
+            break;
+        }
+    }
+
+    dist
+}
+
+fn part2(puzzle: &Grid<u32>) -> usize {
+    let score = |pos, height| {
+        NEIGHBOURS4
+            .iter()
+            .map(|&dir| count_trees(height, sight_line(puzzle, pos, dir)))
+            .product()
+    };
+
+    puzzle
+        .iter()
+        .map(|(pos, &height)| score(pos, height))
+        .max()
+        .unwrap_or(0)
+}
+
+struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day08>;
+
+#[test]
+fn test_day08() {
+    let input = "\
+30373
+25512
+65332
+33549
+35390";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 21);
+    assert_eq!(part2(&input), 8);
+}
@@ -1,4 +1,9 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, marker::PhantomData};
+
+use crate::{
+    error::{parse_at, ParseError},
+    solution::{Solution, Solver},
+};
 
 enum Move {
     Right,
@@ -7,21 +12,19 @@ enum Move {
     Down,
 }
 
-fn parse(input: &str) -> Vec<(Move, i64)> {
+fn parse(input: &str) -> Result<Vec<(Move, i64)>, ParseError> {
     let mut moves = vec![];
-    for line in input
-        .lines()
-        .map(|l| l.split_ascii_whitespace().collect::<Vec<_>>())
-    {
-        moves.push(match line.as_slice() {
-            ["R", dist] => (Move::Right, dist.parse().unwrap()),
-            ["L", dist] => (Move::Left, dist.parse().unwrap()),
-            ["U", dist] => (Move::Up, dist.parse().unwrap()),
-            ["D", dist] => (Move::Down, dist.parse().unwrap()),
-            _ => unreachable!(),
+    for line in input.lines() {
+        let words = line.split_ascii_whitespace().collect::<Vec<_>>();
+        moves.push(match words.as_slice() {
+            ["R", dist] => (Move::Right, parse_at(input, dist)?),
+            ["L", dist] => (Move::Left, parse_at(input, dist)?),
+            ["U", dist] => (Move::Up, parse_at(input, dist)?),
+            ["D", dist] => (Move::Down, parse_at(input, dist)?),
+            _ => return Err(ParseError::at(input, line, "invalid move")),
         })
     }
-    moves
+    Ok(moves)
 }
 
 fn solve(puzzle: &[(Move, i64)], mut tail: Vec<(i64, i64)>) -> usize {
@@ -65,13 +68,28 @@ fn part2(puzzle: &[(Move, i64)]) -> usize {
     solve(puzzle, vec![(0, 0); 9])
 }
 
-fn main() {
-    let input = include_str!("../../input/input09.txt");
-    let input = parse(input);
-    println!("part1 = {}", part1(&input));
-    println!("part2 = {}", part2(&input));
+struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<(Move, i64)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(input))
+    }
 }
 
+pub const SOLVER: &dyn Solver = &PhantomData::<Day09>;
+
 #[test]
 fn test_day09() {
     let input = "\
@@ -83,7 +101,7 @@ R 4
 D 1
 L 5
 R 2";
-    let input = parse(input);
+    let input = parse(input).unwrap();
 
     assert_eq!(part1(&input), 13);
     assert_eq!(part2(&input), 1);
@@ -97,7 +115,7 @@ R 17
 D 10
 L 25
 U 20";
-    let input = parse(input);
+    let input = parse(input).unwrap();
 
     assert_eq!(part2(&input), 36);
 }
@@ -1,22 +1,32 @@
+use std::marker::PhantomData;
+
+use crate::{
+    error::{parse_at, ParseError},
+    solution::{Solution, Solver},
+    visual::{Frame, Recorder},
+};
+
 enum Instruction {
     Add(i64),
     Noop,
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
     let mut instructions = vec![];
 
     for line in input.lines() {
-        let line: Vec<_> = line.split_ascii_whitespace().collect();
+        let words: Vec<_> = line.split_ascii_whitespace().collect();
 
-        instructions.push(match line.as_slice() {
-            ["addx", operand] => Instruction::Add(operand.parse().unwrap()),
+        instructions.push(match words.as_slice() {
+            ["addx", operand] => Instruction::Add(parse_at(input, operand)?),
             ["noop"] => Instruction::Noop,
-            _ => unreachable!(),
+            _ => {
+                return Err(ParseError::at(input, line, "invalid instruction"))
+            }
         });
     }
 
-    instructions
+    Ok(instructions)
 }
 
 struct Handheld {
@@ -35,12 +45,13 @@ impl Handheld {
             screen: [[' '; 40]; 6],
         };
 
-        h.tick();
+        h.tick(&mut Recorder::off());
         h
     }
 
-    fn tick(&mut self) {
+    fn tick(&mut self, recorder: &mut Recorder) {
         self.update_screen();
+        recorder.frame(|| Frame::text(&self.show_screen()[1..]));
         self.clock += 1;
 
         if (20..=220).step_by(40).any(|e| self.clock == e) {
@@ -48,15 +59,19 @@ impl Handheld {
         }
     }
 
-    fn interpret(&mut self, instructions: &[Instruction]) {
+    fn interpret(
+        &mut self,
+        instructions: &[Instruction],
+        recorder: &mut Recorder,
+    ) {
         for ins in instructions {
             match ins {
                 Instruction::Add(v) => {
-                    self.tick();
+                    self.tick(recorder);
                     self.register += v;
-                    self.tick();
+                    self.tick(recorder);
                 }
-                Instruction::Noop => self.tick(),
+                Instruction::Noop => self.tick(recorder),
             }
         }
     }
@@ -78,31 +93,63 @@ impl Handheld {
         }
     }
 
-    fn show_screen(&self) {
-        for row in self.screen {
-            for pixel in row {
-                print!("{}", pixel);
-            }
+    fn show_screen(&self) -> String {
+        let mut screen = String::new();
 
-            println!();
+        for row in self.screen {
+            screen.push('\n');
+            screen.extend(row);
         }
+
+        screen
     }
 }
 
 fn solve(instructions: &[Instruction]) -> i64 {
     let mut cpu = Handheld::new();
 
-    cpu.interpret(instructions);
-    cpu.show_screen();
+    cpu.interpret(instructions, &mut Recorder::off());
     cpu.strength
 }
 
-fn main() {
-    let input = include_str!("../../input/input10.txt");
-    let input = parse(input);
-    println!("part1 = {}", solve(&input));
+fn screen(instructions: &[Instruction]) -> String {
+    let mut cpu = Handheld::new();
+
+    cpu.interpret(instructions, &mut Recorder::off());
+    cpu.show_screen()
 }
 
+struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type Part1 = i64;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(solve(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(screen(input))
+    }
+
+    fn visualise(
+        input: &Self::Input,
+        _: u8,
+        recorder: &mut Recorder,
+    ) -> Result<bool, String> {
+        Handheld::new().interpret(input, recorder);
+        Ok(true)
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day10>;
+
 #[test]
 fn test_day10() {
     let input = "\
@@ -252,7 +299,7 @@ addx -11
 noop
 noop
 noop";
-    let input = parse(input);
+    let input = parse(input).unwrap();
 
     assert_eq!(solve(&input), 13140);
 }
@@ -1,4 +1,10 @@
-use std::collections::VecDeque;
+use std::{collections::VecDeque, marker::PhantomData};
+
+use crate::{
+    error::{parse_at, ParseError},
+    scan,
+    solution::{Solution, Solver},
+};
 
 #[derive(Debug, Clone)]
 enum Operand {
@@ -40,65 +46,46 @@ struct Monkey {
     inspect_count: u64,
 }
 
-fn parse_expr(input: &str) -> Expr {
-    let input = input
-        .chars()
-        .skip_while(|&c| c != '=')
-        .skip(2)
-        .collect::<String>();
-
-    let input = input.split_ascii_whitespace().collect::<Vec<_>>();
-
-    fn parse_operand(input: &str) -> Operand {
-        match input {
-            "old" => Operand::Old,
-            n => Operand::Number(n.parse().unwrap()),
-        }
-    }
+fn parse_expr(input: &str, line: &str) -> Result<Expr, ParseError> {
+    let Some((_, expr)) = line.split_once('=') else {
+        return Err(ParseError::at(input, line, "expected `new = ...`"));
+    };
 
-    match input.as_slice() {
-        [o1, "+", o2] => Expr::Add(parse_operand(o1), parse_operand(o2)),
-        [o1, "*", o2] => Expr::Mul(parse_operand(o1), parse_operand(o2)),
-        _ => unreachable!(),
-    }
-}
+    let expr = expr.split_ascii_whitespace().collect::<Vec<_>>();
 
-fn extract_numbers<T>(input: &str) -> VecDeque<T>
-where
-    T: std::str::FromStr,
-    T::Err: std::fmt::Debug,
-{
-    let mut numbers = VecDeque::new();
-    let mut num = String::new();
-
-    for c in input.chars() {
-        if c.is_numeric() {
-            num.push(c);
-        } else if !num.is_empty() {
-            numbers.push_back(num.parse::<T>().unwrap());
-            num.clear();
-        }
-    }
+    let parse_operand = |operand: &str| match operand {
+        "old" => Ok(Operand::Old),
+        n => parse_at(input, n).map(Operand::Number),
+    };
 
-    if !num.is_empty() {
-        numbers.push_back(num.parse::<T>().unwrap());
+    match expr.as_slice() {
+        [o1, "+", o2] => Ok(Expr::Add(parse_operand(o1)?, parse_operand(o2)?)),
+        [o1, "*", o2] => Ok(Expr::Mul(parse_operand(o1)?, parse_operand(o2)?)),
+        _ => Err(ParseError::at(input, line, "invalid operation")),
     }
-
-    numbers
 }
 
-fn parse(input: &str) -> Vec<Monkey> {
+fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
     let mut monkeys = vec![];
 
     for monkey in input.split("\n\n") {
         let mut attrs = monkey.lines();
-        attrs.next();
+        let mut attr = |name: &str| {
+            let expected = format!("expected `{name}`");
+            match attrs.next() {
+                Some(line) if line.trim_start().starts_with(name) => Ok(line),
+                Some(line) => Err(ParseError::at(input, line, expected)),
+                None => Err(ParseError::after(input, monkey, expected)),
+            }
+        };
 
-        let items: VecDeque<u64> = extract_numbers(attrs.next().unwrap());
-        let operation = parse_expr(attrs.next().unwrap());
-        let test_divisor = extract_numbers(attrs.next().unwrap())[0];
-        let true_monkey = extract_numbers::<usize>(attrs.next().unwrap())[0];
-        let false_monkey = extract_numbers::<usize>(attrs.next().unwrap())[0];
+        attr("Monkey")?;
+        let items = scan::integers(input, attr("Starting items:")?)
+            .collect::<Result<_, _>>()?;
+        let operation = parse_expr(input, attr("Operation:")?)?;
+        let [test_divisor] = scan::numbers(input, attr("Test:")?)?;
+        let [true_monkey] = scan::numbers(input, attr("If true:")?)?;
+        let [false_monkey] = scan::numbers(input, attr("If false:")?)?;
 
         monkeys.push(Monkey {
             items,
@@ -110,7 +97,7 @@ fn parse(input: &str) -> Vec<Monkey> {
         });
     }
 
-    monkeys
+    Ok(monkeys)
 }
 
 fn part1(monkeys: &mut [Monkey]) -> u64 {
@@ -122,7 +109,7 @@ fn part1(monkeys: &mut [Monkey]) -> u64 {
                 item = monkeys[idx].operation.eval(item);
                 item /= 3;
 
-                let dest = if item % monkeys[idx].test_divisor == 0 {
+                let dest = if item.is_multiple_of(monkeys[idx].test_divisor) {
                     monkeys[idx].true_monkey
                 } else {
                     monkeys[idx].false_monkey
@@ -147,7 +134,7 @@ fn part2(monkeys: &mut [Monkey]) -> u64 {
                 monkeys[idx].inspect_count += 1;
                 item = monkeys[idx].operation.eval(item) % prod;
 
-                let dest = if item % monkeys[idx].test_divisor == 0 {
+                let dest = if item.is_multiple_of(monkeys[idx].test_divisor) {
                     monkeys[idx].true_monkey
                 } else {
                     monkeys[idx].false_monkey
@@ -162,13 +149,28 @@ fn part2(monkeys: &mut [Monkey]) -> u64 {
     monkeys.iter().take(2).map(|m| m.inspect_count).product()
 }
 
-fn main() {
-    let input = include_str!("../../input/input11.txt");
-    let mut input = parse(input);
-    println!("part1 = {}", part1(&mut input.clone()));
-    println!("part2 = {}", part2(&mut input));
+struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(&mut input.clone()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(&mut input.clone()))
+    }
 }
 
+pub const SOLVER: &dyn Solver = &PhantomData::<Day11>;
+
 #[test]
 fn test_day11() {
     let input = "\
@@ -199,7 +201,7 @@ Monkey 3:
   Test: divisible by 17
     If true: throw to monkey 0
     If false: throw to monkey 1";
-    let mut input = parse(input);
+    let mut input = parse(input).unwrap();
 
     assert_eq!(part1(&mut input.clone()), 10605);
     assert_eq!(part2(&mut input), 2713310158);
@@ -0,0 +1,113 @@
+use std::marker::PhantomData;
+
+use crate::{
+    error::ParseError,
+    grid::{Grid, GridView, Pos},
+    search,
+    solution::{Solution, Solver},
+};
+
+struct Map {
+    heights: Grid<u8>,
+    start: Pos,
+    end: Pos,
+}
+
+fn parse(input: &str) -> Result<Map, ParseError> {
+    let mut end = None;
+    let mut start = None;
+
+    let heights = Grid::parse(input, |pos, c| match c {
+        'S' if start.is_none() => {
+            start = Some(pos);
+            Ok(b'a')
+        }
+        'E' if end.is_none() => {
+            end = Some(pos);
+            Ok(b'z')
+        }
+        'a'..='z' => Ok(c as u8),
+        _ => Err("invalid height"),
+    })?;
+
+    match (start, end) {
+        (Some(start), Some(end)) => Ok(Map {
+            heights,
+            start,
+            end,
+        }),
+        _ => Err(ParseError::after(input, input, "missing `S` or `E`")),
+    }
+}
+
+/// The positions reachable in one step, climbing at most one level.
+fn neighbors(pos: Pos, map: &Map) -> impl Iterator<Item = Pos> + '_ {
+    let current_height = map.heights[pos];
+
+    map.heights
+        .neighbours4(pos)
+        .filter(move |(_, &h)| h <= current_height + 1)
+        .map(|(npos, _)| npos)
+}
+
+/// The length of the shortest path from the closest of `starts` to the end,
+/// or `None` if the end can't be reached from any of them.
+fn shortest_path(
+    map: &Map,
+    starts: impl IntoIterator<Item = Pos>,
+) -> Option<usize> {
+    search::multi_bfs(starts, |&pos| neighbors(pos, map), |&pos| pos == map.end)
+        .goal()
+        .map(|(_, steps)| steps)
+}
+
+fn part1(map: &Map) -> Option<usize> {
+    shortest_path(map, [map.start])
+}
+
+fn part2(map: &Map) -> Option<usize> {
+    let lowest = map.heights.iter().filter(|(_, &h)| h == b'a');
+
+    shortest_path(map, lowest.map(|(pos, _)| pos))
+}
+
+struct Day12;
+
+impl Solution for Day12 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        part1(input).ok_or_else(|| "`E` can't be reached from `S`".to_owned())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        part2(input)
+            .ok_or_else(|| "`E` can't be reached from any `a`".to_owned())
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day12>;
+
+#[test]
+fn test_day12() {
+    let input = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), Some(31));
+    assert_eq!(part2(&input), Some(29));
+
+    let input = parse("SazE").unwrap();
+
+    assert_eq!(part1(&input), None);
+}
@@ -1,4 +1,11 @@
-use std::{cmp::Ordering, iter::Peekable, str::Chars};
+use std::{
+    cmp::Ordering, iter::Peekable, marker::PhantomData, str::CharIndices,
+};
+
+use crate::{
+    error::{parse_at, ParseError},
+    solution::{Solution, Solver},
+};
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 enum Packet {
@@ -6,59 +13,90 @@ enum Packet {
     Integer(u32),
 }
 
-fn parse_packet(input: &mut Peekable<Chars>) -> Packet {
-    match input.peek() {
-        Some('[') => {
-            input.next();
+fn parse_packet(
+    input: &str,
+    line: &str,
+    chars: &mut Peekable<CharIndices>,
+) -> Result<Packet, ParseError> {
+    match chars.peek() {
+        Some((_, '[')) => {
+            chars.next();
             let mut sub = vec![];
 
-            while let Some(lookahead) = input.peek() {
-                match lookahead {
-                    ']' => {
-                        input.next();
+            loop {
+                match chars.peek() {
+                    Some((_, ']')) => {
+                        chars.next();
                         break;
                     }
-                    ',' => {
-                        input.next();
+                    Some((_, ',')) => {
+                        chars.next();
+                    }
+                    Some(_) => sub.push(parse_packet(input, line, chars)?),
+                    None => {
+                        return Err(ParseError::after(
+                            input,
+                            line,
+                            "expected `]`",
+                        ))
                     }
-                    _ => sub.push(parse_packet(input)),
                 }
             }
 
-            Packet::List(sub)
+            Ok(Packet::List(sub))
         }
-        Some(n) if n.is_numeric() => {
-            let mut num_str = String::new();
+        Some(&(start, n)) if n.is_ascii_digit() => {
+            let mut end = start;
 
-            while let Some(n) = input.peek() {
-                if n.is_numeric() {
-                    num_str.push(*n);
-                    input.next();
+            while let Some(&(idx, n)) = chars.peek() {
+                if n.is_ascii_digit() {
+                    end = idx + 1;
+                    chars.next();
                 } else {
                     break;
                 }
             }
 
-            Packet::Integer(num_str.parse().unwrap())
+            parse_at(input, &line[start..end]).map(Packet::Integer)
+        }
+        Some(&(idx, c)) => Err(ParseError::at(
+            input,
+            &line[idx..idx + c.len_utf8()],
+            "unexpected character",
+        )),
+        None => Err(ParseError::after(input, line, "expected a packet")),
+    }
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Packet, ParseError> {
+    let mut chars = line.char_indices().peekable();
+    let packet = parse_packet(input, line, &mut chars)?;
+
+    match chars.next() {
+        Some((idx, _)) => {
+            Err(ParseError::at(input, &line[idx..], "trailing characters"))
         }
-        _ => unreachable!(),
+        None => Ok(packet),
     }
 }
 
-fn parse(input: &str) -> Vec<(Packet, Packet)> {
+fn parse(input: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
     let groups = input.split("\n\n").collect::<Vec<_>>();
     let mut packets = vec![];
 
     for group in groups {
         let mut lines = group.lines();
-        let first = parse_packet(&mut lines.next().unwrap().chars().peekable());
-        let second =
-            parse_packet(&mut lines.next().unwrap().chars().peekable());
+        let mut next_packet = || match lines.next() {
+            Some(line) => parse_line(input, line),
+            None => Err(ParseError::after(input, group, "expected a packet")),
+        };
+        let first = next_packet()?;
+        let second = next_packet()?;
 
         packets.push((first, second));
     }
 
-    packets
+    Ok(packets)
 }
 
 impl PartialOrd for Packet {
@@ -122,13 +160,28 @@ fn part2(pairs: &[(Packet, Packet)]) -> usize {
         * (packets.binary_search(&make_divider(6)).unwrap() + 1)
 }
 
-fn main() {
-    let input = include_str!("../../input/input13.txt");
-    let input = parse(input);
-    println!("part1 = {}", part1(&input));
-    println!("part2 = {}", part2(&input));
+struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Packet, Packet)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(input))
+    }
 }
 
+pub const SOLVER: &dyn Solver = &PhantomData::<Day13>;
+
 #[test]
 fn test_day13() {
     let input = "\
@@ -155,7 +208,7 @@ fn test_day13() {
 
 [1,[2,[3,[4,[5,6,7]]]],8,9]
 [1,[2,[3,[4,[5,6,0]]]],8,9]";
-    let input = parse(input);
+    let input = parse(input).unwrap();
 
     assert_eq!(part1(&input), 13);
     assert_eq!(part2(&input), 140);
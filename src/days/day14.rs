@@ -0,0 +1,211 @@
+use std::{cmp, marker::PhantomData};
+
+use crate::{
+    error::{parse_at, ParseError},
+    grid::{Bounds, GridView, Pos, SparseGrid},
+    solution::{Solution, Solver},
+    visual::{Frame, Recorder},
+};
+
+#[derive(Clone)]
+struct Rock {
+    structure: SparseGrid<char>,
+    sand_x: i64,
+    max_y: i64,
+}
+
+fn parse(input: &str) -> Result<Rock, ParseError> {
+    let mut paths = vec![];
+    for line in input.lines() {
+        let mut path: Vec<Pos> = vec![];
+
+        for point in line.split(" -> ") {
+            let Some((x, y)) = point.split_once(',') else {
+                return Err(ParseError::at(input, point, "expected `x,y`"));
+            };
+            let (x, y) = (parse_at(input, x)?, parse_at(input, y)?);
+
+            if path.last().is_some_and(|&(px, py)| px != x && py != y) {
+                return Err(ParseError::at(input, point, "diagonal path"));
+            }
+
+            path.push((x, y));
+        }
+
+        paths.push(path);
+    }
+
+    if paths.is_empty() {
+        return Err(ParseError::after(input, input, "no rock paths"));
+    }
+
+    let mut max_y = i64::MIN;
+
+    for path in &paths {
+        for segment in path {
+            max_y = cmp::max(segment.1, max_y);
+        }
+    }
+    let mut structure = SparseGrid::new();
+
+    for path in paths {
+        for segment in path.windows(2) {
+            let begin_x = segment[0].0;
+            let begin_y = segment[0].1;
+            let end_x = segment[1].0;
+            let end_y = segment[1].1;
+
+            if begin_x == end_x {
+                let start_y = cmp::min(begin_y, end_y);
+                let end_y = cmp::max(begin_y, end_y);
+                for y in start_y..=end_y {
+                    structure.insert((begin_x, y), '#');
+                }
+            } else {
+                let start_x = cmp::min(begin_x, end_x);
+                let end_x = cmp::max(begin_x, end_x);
+                for x in start_x..=end_x {
+                    structure.insert((x, begin_y), '#');
+                }
+            }
+        }
+    }
+
+    Ok(Rock {
+        structure,
+        sand_x: 500,
+        max_y,
+    })
+}
+
+/// Draws the cave inside `bounds`, with the floor at `floor` if there is one.
+fn frame(rock: &Rock, bounds: Bounds, floor: Option<i64>) -> Frame {
+    Frame::render(&rock.structure, bounds, |(x, y), cell| match cell {
+        Some(&c) => c,
+        None if (x, y) == (rock.sand_x, 0) => '+',
+        None if Some(y) == floor => '#',
+        None => '.',
+    })
+}
+
+fn part1(rock: &mut Rock, recorder: &mut Recorder) -> i64 {
+    let (mut sand_x, mut sand_y) = (rock.sand_x, 0);
+    let mut resting_sand = 0;
+    let mut bounds = rock.structure.bounds().unwrap_or(Bounds {
+        min: (rock.sand_x, 0),
+        max: (rock.sand_x, 0),
+    });
+    bounds.extend((rock.sand_x, 0));
+
+    loop {
+        if sand_y >= rock.max_y {
+            break;
+        } else if !rock.structure.contains((sand_x, sand_y + 1)) {
+            sand_y += 1;
+        } else if !rock.structure.contains((sand_x - 1, sand_y + 1)) {
+            sand_y += 1;
+            sand_x -= 1;
+        } else if !rock.structure.contains((sand_x + 1, sand_y + 1)) {
+            sand_y += 1;
+            sand_x += 1;
+        } else {
+            rock.structure.insert((sand_x, sand_y), 'O');
+            resting_sand += 1;
+            sand_x = rock.sand_x;
+            sand_y = 0;
+            recorder.frame(|| frame(rock, bounds, None));
+        }
+    }
+
+    resting_sand
+}
+
+fn part2(rock: &mut Rock, recorder: &mut Recorder) -> i64 {
+    let (mut sand_x, mut sand_y) = (rock.sand_x, 0);
+    let mut resting_sand = 0;
+    let floor = rock.max_y + 2;
+    // the sand piles up to a triangle that is as high as the floor is deep
+    let bounds = Bounds {
+        min: (rock.sand_x - floor, 0),
+        max: (rock.sand_x + floor, floor),
+    };
+
+    loop {
+        if sand_y + 1 == floor {
+            rock.structure.insert((sand_x, sand_y), 'O');
+            resting_sand += 1;
+            sand_x = rock.sand_x;
+            sand_y = 0;
+            recorder.frame(|| frame(rock, bounds, Some(floor)));
+        } else if !rock.structure.contains((sand_x, sand_y + 1)) {
+            sand_y += 1;
+        } else if !rock.structure.contains((sand_x - 1, sand_y + 1)) {
+            sand_y += 1;
+            sand_x -= 1;
+        } else if !rock.structure.contains((sand_x + 1, sand_y + 1)) {
+            sand_y += 1;
+            sand_x += 1;
+        } else if (sand_x, sand_y) == (rock.sand_x, 0) {
+            resting_sand += 1;
+            rock.structure.insert((sand_x, sand_y), 'O');
+            recorder.frame(|| frame(rock, bounds, Some(floor)));
+            break;
+        } else {
+            rock.structure.insert((sand_x, sand_y), 'O');
+            resting_sand += 1;
+            sand_x = rock.sand_x;
+            sand_y = 0;
+            recorder.frame(|| frame(rock, bounds, Some(floor)));
+        }
+    }
+
+    resting_sand
+}
+
+struct Day14;
+
+impl Solution for Day14 {
+    type Input = Rock;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(&mut input.clone(), &mut Recorder::off()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(&mut input.clone(), &mut Recorder::off()))
+    }
+
+    fn visualise(
+        input: &Self::Input,
+        part: u8,
+        recorder: &mut Recorder,
+    ) -> Result<bool, String> {
+        match part {
+            1 => part1(&mut input.clone(), recorder),
+            _ => part2(&mut input.clone(), recorder),
+        };
+        Ok(true)
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day14>;
+
+#[test]
+fn test_day14() {
+    let input = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+    let mut input = parse(input).unwrap();
+
+    assert_eq!(part1(&mut input.clone(), &mut Recorder::off()), 24);
+    assert_eq!(part2(&mut input, &mut Recorder::off()), 93);
+
+    let err = parse("").err().unwrap();
+    assert_eq!(err.message, "no rock paths");
+}
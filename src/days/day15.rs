@@ -1,29 +1,23 @@
 use rayon::prelude::*;
-use std::mem;
-
-fn parse(input: &str) -> Vec<[i64; 4]> {
-    let mut sensor_list = vec![];
-
-    for line in input.lines() {
-        let mut points = [0; 4];
-        let mut idx = 0;
-        let mut current_num_str = String::new();
-
-        for c in line.chars() {
-            if c.is_numeric() || c == '-' {
-                current_num_str.push(c);
-            } else if !current_num_str.is_empty() {
-                points[idx] = current_num_str.parse().unwrap();
-                idx += 1;
-                current_num_str.clear();
-            }
-        }
-
-        points[idx] = current_num_str.parse().unwrap();
-        sensor_list.push(points);
-    }
-
-    sensor_list
+use std::{marker::PhantomData, mem};
+
+use crate::{
+    error::ParseError,
+    scan,
+    solution::{Solution, Solver},
+};
+
+fn parse(input: &str) -> Result<Vec<[i64; 4]>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            scan::template(
+                input,
+                line,
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            )
+        })
+        .collect()
 }
 
 fn manhattan_distance(x1: i64, y1: i64, x2: i64, y2: i64) -> i64 {
@@ -92,42 +86,55 @@ fn part1(ranges: &[[i64; 4]], y: i64) -> i64 {
         .sum()
 }
 
-fn part2(ranges: &[[i64; 4]], y_max: i64) -> i64 {
-    (0..=y_max)
-        .into_par_iter()
-        .find_map_first(|y| {
-            let mut x_ranges = vec![];
-            for &[sensor_x, sensor_y, beacon_x, beacon_y] in ranges {
-                let range_dist =
-                    manhattan_distance(sensor_x, sensor_y, beacon_x, beacon_y);
-                let y_dist = sensor_y.abs_diff(y) as i64;
-                if range_dist >= y_dist {
-                    let x_pos1 = sensor_x - (range_dist - y_dist);
-                    let x_pos2 = sensor_x + (range_dist - y_dist);
-
-                    x_ranges.push((x_pos1, x_pos2));
-                }
+fn part2(ranges: &[[i64; 4]], y_max: i64) -> Option<i64> {
+    (0..=y_max).into_par_iter().find_map_first(|y| {
+        let mut x_ranges = vec![];
+        for &[sensor_x, sensor_y, beacon_x, beacon_y] in ranges {
+            let range_dist =
+                manhattan_distance(sensor_x, sensor_y, beacon_x, beacon_y);
+            let y_dist = sensor_y.abs_diff(y) as i64;
+            if range_dist >= y_dist {
+                let x_pos1 = sensor_x - (range_dist - y_dist);
+                let x_pos2 = sensor_x + (range_dist - y_dist);
+
+                x_ranges.push((x_pos1, x_pos2));
             }
+        }
 
-            let intervals = simplify_intervals(&mut x_ranges);
+        let intervals = simplify_intervals(&mut x_ranges);
 
-            // find the gap
-            if intervals.len() == 2 {
-                Some((intervals[0].1 + 1) * 4_000_000 + y)
-            } else {
-                None
-            }
-        })
-        .unwrap()
+        // find the gap
+        if intervals.len() == 2 {
+            Some((intervals[0].1 + 1) * 4_000_000 + y)
+        } else {
+            None
+        }
+    })
 }
 
-fn main() {
-    let input = include_str!("../../input/input15.txt");
-    let input = parse(input);
-    println!("part1 = {}", part1(&input, 2_000_000));
-    println!("part2 = {}", part2(&input, 4_000_000));
+struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<[i64; 4]>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input, 2_000_000))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        part2(input, 4_000_000)
+            .ok_or_else(|| "no gap for the distress beacon".to_owned())
+    }
 }
 
+pub const SOLVER: &dyn Solver = &PhantomData::<Day15>;
+
 #[test]
 fn test_day15() {
     let input = "\
@@ -145,8 +152,9 @@ Sensor at x=17, y=20: closest beacon is at x=21, y=22
 Sensor at x=16, y=7: closest beacon is at x=15, y=3
 Sensor at x=14, y=3: closest beacon is at x=15, y=3
 Sensor at x=20, y=1: closest beacon is at x=15, y=3";
-    let input = parse(input);
+    let input = parse(input).unwrap();
 
     assert_eq!(part1(&input, 10), 26);
-    assert_eq!(part2(&input, 20), 56000011);
+    assert_eq!(part2(&input, 20), Some(56000011));
+    assert_eq!(part2(&parse("").unwrap(), 20), None);
 }
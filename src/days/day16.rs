@@ -0,0 +1,619 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use ahash::AHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{parse_at, ParseError},
+    search,
+    solution::{Solution, Solver},
+};
+
+#[derive(Debug, PartialEq, Clone)]
+struct Node {
+    id: usize,
+    name: String,
+    flow: i64,
+    adjacent: Vec<String>,
+    adjacent_id: Vec<usize>,
+}
+
+/// The parsed valves, indexed by their line, together with the length of
+/// the shortest path between every pair of them.
+#[derive(Debug)]
+struct Valves {
+    nodes: Vec<Node>,
+    ids: AHashMap<String, usize>,
+    dist: Vec<Option<usize>>,
+}
+
+impl Valves {
+    fn new(nodes: Vec<Node>, ids: AHashMap<String, usize>) -> Self {
+        let n = nodes.len();
+        let mut dist = vec![None; n * n];
+
+        for from in 0..n {
+            let paths = search::bfs(
+                from,
+                |&id| nodes[id].adjacent_id.iter().copied(),
+                |_| false,
+            );
+
+            for (&to, cost) in paths.iter() {
+                dist[from * n + to] = Some(cost);
+            }
+        }
+
+        Valves { nodes, ids, dist }
+    }
+
+    fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    /// The number of minutes to walk from one valve to another, or `None` if
+    /// no tunnels connect them.
+    fn distance(&self, from: usize, to: usize) -> Option<usize> {
+        self.dist[from * self.nodes.len() + to]
+    }
+
+    /// A shortest walk from one valve to another, including both ends.
+    fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        let mut path = vec![from];
+        let mut left = self.distance(from, to)?;
+
+        while left > 0 {
+            let pos = *path.last().unwrap();
+            let next = self.nodes[pos]
+                .adjacent_id
+                .iter()
+                .copied()
+                .find(|&next| self.distance(next, to) == Some(left - 1))
+                .unwrap();
+            path.push(next);
+            left -= 1;
+        }
+
+        Some(path)
+    }
+}
+
+fn parse(input: &str) -> Result<Valves, ParseError> {
+    let mut nodes = vec![];
+    let mut adj = AHashMap::new();
+    let mut tunnels = vec![];
+
+    for (id, line) in input.lines().enumerate() {
+        let words = line.split_ascii_whitespace().collect::<Vec<_>>();
+        let ["Valve", name, "has", "flow", flow, _, _, "to", _, adjacent @ ..] =
+            words.as_slice()
+        else {
+            return Err(ParseError::at(input, line, "invalid valve"));
+        };
+
+        let flow = flow.trim_start_matches("rate=").trim_end_matches(';');
+        let flow: i64 = parse_at(input, flow)?;
+        let name = name.to_string();
+
+        let adjacent: Vec<_> =
+            adjacent.iter().map(|w| w.trim_end_matches(',')).collect();
+
+        if adj.insert(name.clone(), id).is_some() {
+            return Err(ParseError::at(input, words[1], "duplicate valve"));
+        }
+        tunnels.push((id, adjacent.clone()));
+
+        let adjacent = adjacent.into_iter().map(str::to_owned).collect();
+
+        nodes.push(Node {
+            id,
+            name,
+            flow,
+            adjacent,
+            adjacent_id: vec![],
+        });
+    }
+
+    for (id, adjacent) in tunnels {
+        for adj_name in adjacent {
+            let adj_id = adj.get(adj_name).ok_or_else(|| {
+                ParseError::at(input, adj_name, "unknown valve")
+            })?;
+            nodes[id].adjacent_id.push(*adj_id);
+        }
+    }
+
+    // Both parts and the reports start at `AA`.
+    if !adj.contains_key("AA") {
+        return Err(ParseError::after(input, input, "missing valve `AA`"));
+    }
+
+    Ok(Valves::new(nodes, adj))
+}
+
+/// The valves worth opening: their ids, flow rates and the distances between
+/// them. The start valve comes last, after the valves with a flow.
+struct Network {
+    ids: Vec<usize>,
+    flows: Vec<i64>,
+    dist: Vec<Vec<Option<i64>>>,
+}
+
+impl Network {
+    fn new(valves: &Valves, start: &str) -> Self {
+        let mut ids = valves
+            .nodes
+            .iter()
+            .filter(|node| node.flow > 0)
+            .map(|node| node.id)
+            .collect::<Vec<_>>();
+        let flows = ids.iter().map(|&id| valves.nodes[id].flow).collect();
+
+        let start = valves
+            .id(start)
+            .unwrap_or_else(|| panic!("no valve `{start}`"));
+        ids.push(start);
+
+        let dist = ids
+            .iter()
+            .map(|&from| {
+                ids.iter()
+                    .map(|&to| valves.distance(from, to).map(|d| d as i64))
+                    .collect()
+            })
+            .collect();
+
+        Network { ids, flows, dist }
+    }
+
+    /// The best a single agent can do within `minutes` for every subset of
+    /// the valves.
+    fn best_per_subset(&self, minutes: i64) -> Subsets {
+        let valves = self.flows.len();
+        let mut best = Subsets {
+            pressure: vec![0; 1 << valves],
+            routes: vec![vec![]; 1 << valves],
+            source: (0..1 << valves).collect(),
+        };
+
+        self.explore(valves, minutes, 0, 0, &mut vec![], &mut best);
+
+        // Also allow leaving valves of a subset closed.
+        for valve in 0..valves {
+            for mask in 0..best.pressure.len() {
+                let fewer = mask ^ 1 << valve;
+                if mask & 1 << valve != 0
+                    && best.pressure[fewer] > best.pressure[mask]
+                {
+                    best.pressure[mask] = best.pressure[fewer];
+                    best.source[mask] = best.source[fewer];
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Visits every order of opening valves that fits into the time left.
+    fn explore(
+        &self,
+        pos: usize,
+        time_left: i64,
+        opened: usize,
+        pressure: i64,
+        route: &mut Vec<usize>,
+        best: &mut Subsets,
+    ) {
+        if pressure > best.pressure[opened] {
+            best.pressure[opened] = pressure;
+            best.routes[opened].clone_from(route);
+        }
+
+        for next in 0..self.flows.len() {
+            let Some(dist) = self.dist[pos][next] else {
+                continue;
+            };
+            let time_left = time_left - dist - 1;
+
+            if opened & 1 << next == 0 && time_left > 0 {
+                let pressure = pressure + time_left * self.flows[next];
+                route.push(next);
+                self.explore(
+                    next,
+                    time_left,
+                    opened | 1 << next,
+                    pressure,
+                    route,
+                    best,
+                );
+                route.pop();
+            }
+        }
+    }
+}
+
+/// The best pressures per subset of valves, indexed by its bitmask.
+struct Subsets {
+    /// The highest pressure released by opening the valves of the subset, or
+    /// fewer of them.
+    pressure: Vec<i64>,
+    /// The order to open exactly the valves of a subset in.
+    routes: Vec<Vec<usize>>,
+    /// The subset whose route achieves the pressure.
+    source: Vec<usize>,
+}
+
+impl Subsets {
+    fn route(&self, mask: usize) -> &[usize] {
+        &self.routes[self.source[mask]]
+    }
+}
+
+/// The order in which each of `agents` agents working in parallel opens
+/// valves to release the most pressure in `minutes`, and that pressure. Every
+/// agent opens a disjoint subset of the valves, so the best results per
+/// subset are combined over all ways to split the valves.
+fn best_routes(
+    network: &Network,
+    minutes: i64,
+    agents: usize,
+) -> (i64, Vec<Vec<usize>>) {
+    let best = network.best_per_subset(minutes);
+    let full = best.pressure.len() - 1;
+
+    if agents == 0 {
+        return (0, vec![]);
+    }
+
+    // With `total` being the best for k agents per subset, the best for k + 1
+    // agents splits each subset into one part for the new agent and the rest.
+    // Returns that part along with the pressure.
+    let combine = |total: &[i64], mask: usize| {
+        let mut result = (total[mask], 0);
+        let mut sub = mask;
+
+        while sub > 0 {
+            let pressure = best.pressure[sub] + total[mask ^ sub];
+            if pressure > result.0 {
+                result = (pressure, sub);
+            }
+            sub = (sub - 1) & mask;
+        }
+
+        result
+    };
+
+    let mut total = best.pressure.clone();
+    let mut splits: Vec<Vec<usize>> = vec![];
+    for _ in 2..agents {
+        let (next, split) =
+            (0..=full).map(|mask| combine(&total, mask)).unzip();
+        total = next;
+        splits.push(split);
+    }
+
+    let (pressure, last) = match agents {
+        1 => (total[full], full),
+        _ => combine(&total, full),
+    };
+
+    // Hand out the subsets again, from the last agent back to the first.
+    let mut routes = vec![best.route(last).to_vec()];
+    let mut rest = full ^ last;
+    for split in splits.iter().rev() {
+        let sub = split[rest];
+        routes.push(best.route(sub).to_vec());
+        rest ^= sub;
+    }
+    if agents > 1 {
+        routes.push(best.route(rest).to_vec());
+    }
+    routes.reverse();
+
+    (pressure, routes)
+}
+
+/// What an agent does in one minute: walk through a tunnel or open the valve
+/// it stands at, which releases `pressure` until the time is up.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Step {
+    minute: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    moved_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opened: Option<String>,
+    pressure: i64,
+}
+
+/// The steps of every agent, in order. Minutes without a step are spent
+/// waiting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Plan {
+    minutes: i64,
+    pressure: i64,
+    agents: Vec<Vec<Step>>,
+}
+
+impl Plan {
+    /// All steps as a table ordered by minute, then agent.
+    fn table(&self) -> String {
+        let mut steps = self
+            .agents
+            .iter()
+            .enumerate()
+            .flat_map(|(agent, steps)| steps.iter().map(move |s| (agent, s)))
+            .collect::<Vec<_>>();
+        steps.sort_by_key(|&(agent, step)| (step.minute, agent));
+
+        let mut table =
+            "minute  agent  moved to  opened  pressure\n".to_owned();
+        for (agent, step) in steps {
+            let name =
+                |valve: &Option<String>| valve.clone().unwrap_or_default();
+            let pressure = match step.opened {
+                Some(_) => step.pressure.to_string(),
+                None => String::new(),
+            };
+            let row = format!(
+                "{:>6}  {:>5}  {:<8}  {:<6}  {:>8}",
+                step.minute,
+                agent + 1,
+                name(&step.moved_to),
+                name(&step.opened),
+                pressure
+            );
+            writeln!(table, "{}", row.trim_end()).unwrap();
+        }
+        writeln!(table, "{:<33}  {:>8}", "total", self.pressure).unwrap();
+
+        table
+    }
+}
+
+/// The plan for `agents` agents starting at `AA` that releases the most
+/// pressure in `minutes`.
+fn schedule(valves: &Valves, minutes: i64, agents: usize) -> Plan {
+    let network = Network::new(valves, "AA");
+    let (pressure, routes) = best_routes(&network, minutes, agents);
+    let start = *network.ids.last().unwrap();
+
+    let agents = routes
+        .into_iter()
+        .map(|route| {
+            let mut steps = vec![];
+            let mut pos = start;
+
+            for valve in route {
+                let id = network.ids[valve];
+                let name = &valves.nodes[id].name;
+
+                for next in &valves.path(pos, id).unwrap()[1..] {
+                    steps.push(Step {
+                        minute: steps.len() as i64 + 1,
+                        moved_to: Some(valves.nodes[*next].name.clone()),
+                        opened: None,
+                        pressure: 0,
+                    });
+                }
+
+                let minute = steps.len() as i64 + 1;
+                steps.push(Step {
+                    minute,
+                    moved_to: None,
+                    opened: Some(name.clone()),
+                    pressure: (minutes - minute) * network.flows[valve],
+                });
+                pos = id;
+            }
+
+            steps
+        })
+        .collect();
+
+    Plan {
+        minutes,
+        pressure,
+        agents,
+    }
+}
+
+/// Replays `plan` minute by minute, using nothing but the tunnels. Returns
+/// the pressure released, or the first step that cannot be taken or does not
+/// add up.
+fn simulate(valves: &Valves, start: &str, plan: &Plan) -> Result<i64, String> {
+    let valve = |name: &str| {
+        valves.id(name).ok_or_else(|| format!("no valve `{name}`"))
+    };
+    let start = valve(start)?;
+
+    let mut positions = vec![start; plan.agents.len()];
+    let mut next = vec![0; plan.agents.len()];
+    let mut open = vec![false; valves.nodes.len()];
+    let (mut flow, mut released) = (0, 0);
+
+    for minute in 1..=plan.minutes {
+        released += flow;
+
+        for (agent, steps) in plan.agents.iter().enumerate() {
+            let Some(step) = steps.get(next[agent]) else {
+                continue;
+            };
+            if step.minute != minute {
+                continue;
+            }
+            next[agent] += 1;
+
+            let pos = &mut positions[agent];
+            let at = |msg: String| {
+                format!("agent {} minute {minute}: {msg}", agent + 1)
+            };
+
+            match (&step.moved_to, &step.opened) {
+                (Some(to), None) => {
+                    let to = valve(to).map_err(at)?;
+                    if !valves.nodes[*pos].adjacent_id.contains(&to) {
+                        return Err(at(format!(
+                            "no tunnel from {} to {}",
+                            valves.nodes[*pos].name, valves.nodes[to].name
+                        )));
+                    }
+                    *pos = to;
+                }
+                (None, Some(name)) => {
+                    let id = valve(name).map_err(at)?;
+                    if id != *pos {
+                        return Err(at(format!("not at valve {name}")));
+                    }
+                    if open[id] {
+                        return Err(at(format!("{name} is already open")));
+                    }
+                    open[id] = true;
+                    flow += valves.nodes[id].flow;
+
+                    let pressure =
+                        (plan.minutes - minute) * valves.nodes[id].flow;
+                    if step.pressure != pressure {
+                        return Err(at(format!(
+                            "opening {name} releases {pressure}, not {}",
+                            step.pressure
+                        )));
+                    }
+                }
+                _ => return Err(at("has to either move or open".to_owned())),
+            }
+        }
+    }
+
+    for (agent, steps) in plan.agents.iter().enumerate() {
+        if let Some(step) = steps.get(next[agent]) {
+            return Err(format!(
+                "agent {} minute {}: step out of order or out of time",
+                agent + 1,
+                step.minute
+            ));
+        }
+    }
+
+    if released != plan.pressure {
+        return Err(format!("plan releases {released}, not {}", plan.pressure));
+    }
+
+    Ok(released)
+}
+
+fn part1(valves: &Valves) -> i64 {
+    schedule(valves, 30, 1).pressure
+}
+
+fn part2(valves: &Valves) -> i64 {
+    schedule(valves, 26, 2).pressure
+}
+
+struct Day16;
+
+impl Solution for Day16 {
+    type Input = Valves;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(input))
+    }
+
+    fn report(
+        input: &Self::Input,
+        name: &str,
+    ) -> Option<Result<String, String>> {
+        let (agents, minutes, json) = match name {
+            "schedule1" => (1, 30, false),
+            "schedule2" => (2, 26, false),
+            "schedule1.json" => (1, 30, true),
+            "schedule2.json" => (2, 26, true),
+            _ => return None,
+        };
+        let plan = schedule(input, minutes, agents);
+        if let Err(e) = simulate(input, "AA", &plan) {
+            return Some(Err(format!("invalid schedule, {e}")));
+        }
+
+        Some(Ok(match json {
+            true => serde_json::to_string_pretty(&plan).unwrap() + "\n",
+            false => plan.table(),
+        }))
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day16>;
+
+#[test]
+fn test_day16() {
+    let input = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 1651);
+    assert_eq!(part2(&input), 1707);
+
+    assert_eq!(schedule(&input, 26, 1).pressure, 1327);
+    assert_eq!(schedule(&input, 1, 2).pressure, 0);
+    // With an agent per valve, every valve opens as early as possible.
+    assert_eq!(schedule(&input, 26, 6).pressure, 1830);
+
+    let mut plan = schedule(&input, 30, 1);
+    let json = serde_json::to_string(&plan).unwrap();
+    assert_eq!(serde_json::from_str::<Plan>(&json).unwrap(), plan);
+    assert_eq!(simulate(&input, "AA", &plan), Ok(1651));
+    let table = plan.table();
+    let lines = table.lines().collect::<Vec<_>>();
+    assert_eq!(lines[1], "     1      1  DD");
+    assert_eq!(lines[2], "     2      1            DD           560");
+    plan.agents[0][1].pressure += 1;
+    assert_eq!(
+        simulate(&input, "AA", &plan),
+        Err("agent 1 minute 2: opening DD releases 560, not 561".to_owned())
+    );
+
+    let err = parse("Valve BB has flow rate=1; tunnel leads to valve BB")
+        .unwrap_err();
+    assert_eq!(err.message, "missing valve `AA`");
+
+    let (aa, hh) = (input.id("AA").unwrap(), input.id("HH").unwrap());
+    assert_eq!(input.distance(aa, hh), Some(5));
+    assert_eq!(input.distance(hh, aa), Some(5));
+    assert_eq!(input.distance(aa, aa), Some(0));
+
+    // A long corridor with more than 64 valves, the most the old distance
+    // cache could hold with two 6-bit valve ids per key.
+    let mut corridor = (0..100)
+        .map(|i| {
+            let flow = if i == 99 { 10 } else { 0 };
+            let next = (i + 1) % 100;
+            let tunnel = format!("tunnel leads to valve V{next}");
+            format!("Valve V{i} has flow rate={flow}; {tunnel}")
+        })
+        .collect::<Vec<_>>();
+    corridor[0] = corridor[0].replace("V0 ", "AA ").replace("V1", "V1, ZZ");
+    corridor[99] = corridor[99].replace("V0", "AA");
+    corridor.push("Valve ZZ has flow rate=0; tunnel leads to valve ZZ".into());
+    let input = parse(&corridor.join("\n")).unwrap();
+    assert_eq!(input.distance(0, 99), Some(99));
+    assert_eq!(input.distance(100, 0), None);
+    assert_eq!(schedule(&input, 120, 1).pressure, 200);
+}
@@ -1,19 +1,38 @@
+use std::marker::PhantomData;
+
 use ahash::AHashMap;
 
+use crate::{
+    error::ParseError,
+    solution::{Solution, Solver},
+    visual::{Frame, Recorder},
+};
+
 enum Dir {
     Left,
     Right,
 }
 
-fn parse(input: &str) -> Vec<Dir> {
-    input
-        .chars()
-        .flat_map(|c| match c {
-            '<' => Some(Dir::Left),
-            '>' => Some(Dir::Right),
-            _ => None,
+fn parse(input: &str) -> Result<Vec<Dir>, ParseError> {
+    let jets = input
+        .trim_end()
+        .char_indices()
+        .map(|(idx, c)| match c {
+            '<' => Ok(Dir::Left),
+            '>' => Ok(Dir::Right),
+            _ => Err(ParseError::at(
+                input,
+                &input[idx..idx + c.len_utf8()],
+                "invalid jet direction",
+            )),
         })
-        .collect()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if jets.is_empty() {
+        return Err(ParseError::after(input, input, "missing jets"));
+    }
+
+    Ok(jets)
 }
 
 #[derive(Clone)]
@@ -124,28 +143,32 @@ struct Chamber {
 }
 
 impl Chamber {
-    #[allow(unused)]
-    fn show(&self, rock: &Rock) {
-        let mut rock_pos = rock
+    /// Draws the top `rows` of the chamber with the falling rock as `@`.
+    fn frame(&self, rock: &Rock, rows: usize) -> Frame {
+        let rock_pos: Vec<_> = rock
             .blocks
             .iter()
-            .map(|(px, py)| (rock.position.0 + px, rock.position.1 - py));
+            .map(|(px, py)| (rock.position.0 + px, rock.position.1 - py))
+            .collect();
+        let mut text = String::new();
+        let top = self.field.len();
 
-        for y in (0..self.field.len()).rev() {
+        for y in (top.saturating_sub(rows)..top).rev() {
             for x in 0..self.field[0].len() {
-                if rock_pos.any(|pos| pos == (x, y)) {
-                    print!("@");
+                if rock_pos.contains(&(x, y)) {
+                    text.push('@');
                 } else {
-                    print!("{}", self.field[y][x]);
+                    text.push(self.field[y][x]);
                 }
             }
-            println!();
+            text.push('\n');
         }
-        println!();
+
+        Frame::text(&text)
     }
 }
 
-fn solve(dirs: &[Dir], limit: usize) -> usize {
+fn solve(dirs: &[Dir], limit: usize, recorder: &mut Recorder) -> usize {
     let mut chamber = Chamber {
         field: vec![['.'; 7]; 4],
     };
@@ -166,7 +189,7 @@ fn solve(dirs: &[Dir], limit: usize) -> usize {
         rock.position.1 = chamber.field.len() - 1;
 
         for (wind_id, wind) in dirs.by_ref() {
-            // chamber.show(&rock);
+            recorder.frame(|| chamber.frame(&rock, 40));
 
             match wind {
                 Dir::Left => rock.left(&chamber),
@@ -200,8 +223,6 @@ fn solve(dirs: &[Dir], limit: usize) -> usize {
 
                 break;
             }
-
-            // chamber.show(&rock);
         }
 
         count += 1;
@@ -213,17 +234,47 @@ fn solve(dirs: &[Dir], limit: usize) -> usize {
     skipped_height + max_height
 }
 
-fn main() {
-    let input = include_str!("../../input/input17.txt");
-    let input = parse(input);
-    println!("part1 = {}", solve(&input, 2022));
-    println!("part2 = {}", solve(&input, 1000000000000));
+struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Dir>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(solve(input, 2022, &mut Recorder::off()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(solve(input, 1000000000000, &mut Recorder::off()))
+    }
+
+    fn visualise(
+        input: &Self::Input,
+        part: u8,
+        recorder: &mut Recorder,
+    ) -> Result<bool, String> {
+        match part {
+            1 => solve(input, 2022, recorder),
+            _ => solve(input, 1000000000000, recorder),
+        };
+        Ok(true)
+    }
 }
 
+pub const SOLVER: &dyn Solver = &PhantomData::<Day17>;
+
 #[test]
 fn test_day17() {
     let input = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
-    let input = parse(input);
+    let input = parse(input).unwrap();
+
+    assert_eq!(solve(&input, 2022, &mut Recorder::off()), 3068);
 
-    assert_eq!(solve(&input, 2022), 3068);
+    let err = parse("\n\n").err().unwrap();
+    assert_eq!(err.message, "missing jets");
 }
@@ -0,0 +1,140 @@
+use std::marker::PhantomData;
+
+use ahash::AHashSet;
+
+use crate::{
+    error::{parse_at, ParseError},
+    search,
+    solution::{Solution, Solver},
+};
+
+fn parse(input: &str) -> Result<AHashSet<[i64; 3]>, ParseError> {
+    let mut cubes = AHashSet::default();
+    for line in input.lines() {
+        let mut cube = [0; 3];
+        let mut elems = line.split(',');
+
+        for side in cube.iter_mut() {
+            *side = match elems.next() {
+                Some(elem) => parse_at(input, elem)?,
+                None => {
+                    return Err(ParseError::after(input, line, "expected `,`"))
+                }
+            };
+        }
+
+        if let Some(elem) = elems.next() {
+            return Err(ParseError::at(input, elem, "unexpected coordinate"));
+        }
+
+        cubes.insert(cube);
+    }
+    Ok(cubes)
+}
+
+fn dirs(&[x, y, z]: &[i64; 3]) -> Vec<[i64; 3]> {
+    vec![
+        [x + 1, y, z],
+        [x - 1, y, z],
+        [x, y + 1, z],
+        [x, y - 1, z],
+        [x, y, z + 1],
+        [x, y, z - 1],
+    ]
+}
+
+fn part1(cubes: &AHashSet<[i64; 3]>) -> i64 {
+    let mut total_area = 0;
+
+    for cube in cubes {
+        for dir in dirs(cube) {
+            if !cubes.contains(&dir) {
+                total_area += 1;
+            }
+        }
+    }
+
+    total_area
+}
+
+fn part2(cubes: &AHashSet<[i64; 3]>) -> i64 {
+    let mut min = [i64::MAX; 3];
+    let mut max = [i64::MIN; 3];
+
+    for cube in cubes {
+        for axis in 0..3 {
+            min[axis] = i64::min(min[axis], cube[axis] - 1);
+            max[axis] = i64::max(max[axis], cube[axis] + 1);
+        }
+    }
+
+    let inside =
+        |w: &[i64; 3]| (0..3).all(|a| (min[a]..=max[a]).contains(&w[a]));
+
+    // flood the box around the droplet, starting in a corner
+    let water = search::bfs(
+        min,
+        |w| {
+            dirs(w)
+                .into_iter()
+                .filter(|w| inside(w) && !cubes.contains(w))
+        },
+        |_| false,
+    );
+
+    let mut total_area = 0;
+
+    for (w, _) in water.iter() {
+        for dir in dirs(w) {
+            if cubes.contains(&dir) {
+                total_area += 1;
+            }
+        }
+    }
+
+    total_area
+}
+
+struct Day18;
+
+impl Solution for Day18 {
+    type Input = AHashSet<[i64; 3]>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day18>;
+
+#[test]
+fn test_day18() {
+    let input = "\
+2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 64);
+    assert_eq!(part2(&input), 58);
+}
@@ -0,0 +1,972 @@
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use rayon::prelude::*;
+use serde::Serialize;
+use std::{
+    cmp::Reverse,
+    fmt::Write,
+    marker::PhantomData,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::{parse_at, ParseError},
+    solution::{Solution, Solver},
+};
+
+/// A robot that collects one unit of `resource` per minute, and how much of
+/// every resource it costs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Recipe {
+    resource: usize,
+    costs: Vec<i64>,
+}
+
+/// Recipes for robots over named resources. There is one robot of the first
+/// recipe to start with, and the robots of the last recipe collect the
+/// resource to maximise, geodes in the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Blueprint {
+    id: i64,
+    resources: Vec<String>,
+    recipes: Vec<Recipe>,
+}
+
+impl Blueprint {
+    fn target(&self) -> usize {
+        self.recipes.last().unwrap().resource
+    }
+
+    /// The robot that collects the resource called `name`.
+    fn robot(&self, name: &str) -> Option<Robot> {
+        self.recipes
+            .iter()
+            .position(|recipe| self.resources[recipe.resource] == name)
+    }
+
+    /// The name of the resource `robot` collects.
+    fn name(&self, robot: Robot) -> &str {
+        &self.resources[self.recipes[robot].resource]
+    }
+}
+
+/// A robot to buy, as the index of its recipe in the blueprint.
+type Robot = usize;
+
+/// Parses lines like `Blueprint 1: Each ore robot costs 4 ore. Each obsidian
+/// robot costs 3 ore and 14 clay.`, where costs can also be separated by
+/// commas.
+fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let mut prints = vec![];
+
+    for line in input.lines() {
+        let Some((head, body)) = line.split_once(':') else {
+            return Err(ParseError::at(input, line, "invalid blueprint"));
+        };
+        let ["Blueprint", id] =
+            head.split_ascii_whitespace().collect::<Vec<_>>()[..]
+        else {
+            return Err(ParseError::at(input, head, "invalid blueprint"));
+        };
+
+        let mut resources: Vec<String> = vec![];
+        let mut resource =
+            |name: &str| match resources.iter().position(|r| r == name) {
+                Some(idx) => idx,
+                None => {
+                    resources.push(name.to_owned());
+                    resources.len() - 1
+                }
+            };
+        let mut recipes: Vec<(usize, Vec<(usize, i64)>)> = vec![];
+
+        for sentence in body.split('.').map(str::trim).filter(|s| !s.is_empty())
+        {
+            let words = sentence.split_ascii_whitespace().collect::<Vec<_>>();
+            let ["Each", robot, "robot", "costs", costs @ ..] =
+                words.as_slice()
+            else {
+                return Err(ParseError::at(input, sentence, "invalid recipe"));
+            };
+
+            let robot_idx = resource(robot);
+            if recipes.iter().any(|(r, _)| *r == robot_idx) {
+                return Err(ParseError::at(input, robot, "duplicate robot"));
+            }
+
+            let costs = costs
+                .iter()
+                .map(|word| word.trim_end_matches(','))
+                .filter(|&word| word != "and")
+                .collect::<Vec<_>>();
+            if costs.is_empty() || costs.len() % 2 != 0 {
+                return Err(ParseError::at(input, sentence, "invalid costs"));
+            }
+
+            let mut recipe = vec![];
+            for cost in costs.chunks(2) {
+                let amount = parse_at(input, cost[0])?;
+                let idx = resource(cost[1]);
+                if recipe.iter().any(|&(r, _)| r == idx) {
+                    return Err(ParseError::at(
+                        input,
+                        cost[1],
+                        "duplicate cost",
+                    ));
+                }
+                recipe.push((idx, amount));
+            }
+
+            recipes.push((robot_idx, recipe));
+        }
+
+        if recipes.is_empty() {
+            return Err(ParseError::at(input, line, "no recipes"));
+        }
+
+        let recipes = recipes
+            .into_iter()
+            .map(|(resource, costs)| {
+                let mut dense = vec![0; resources.len()];
+                for (idx, amount) in costs {
+                    dense[idx] = amount;
+                }
+                Recipe {
+                    resource,
+                    costs: dense,
+                }
+            })
+            .collect();
+
+        prints.push(Blueprint {
+            id: parse_at(input, id)?,
+            resources,
+            recipes,
+        });
+    }
+
+    Ok(prints)
+}
+
+/// A robot to buy, and the minute to buy it in. Without a minute it is
+/// bought as soon as it is affordable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Purchase {
+    robot: Robot,
+    minute: Option<usize>,
+}
+
+/// Plays through `minutes`, buying the robots of `buylist` in order, and
+/// passes every minute to `record` along with the robot bought in it and the
+/// robots and resources at its end. Returns how much of the target resource
+/// was collected and how many robots were bought, or why a purchase planned
+/// for a certain minute could not be made.
+fn replay(
+    blueprint: &Blueprint,
+    buylist: impl IntoIterator<Item = Purchase>,
+    minutes: usize,
+    mut record: impl FnMut(usize, Option<Robot>, &[i64], &[i64]),
+) -> Result<(i64, usize), String> {
+    let mut buylist = buylist.into_iter().peekable();
+    let mut robots = vec![0; blueprint.resources.len()];
+    let mut resources = vec![0; blueprint.resources.len()];
+    let mut bought = 0;
+
+    robots[blueprint.recipes[0].resource] = 1;
+
+    for minute in 1..=minutes {
+        let purchase = buylist.peek().copied().filter(|purchase| {
+            purchase.minute.is_none_or(|planned| planned <= minute)
+        });
+
+        let recipe =
+            purchase.map(|purchase| &blueprint.recipes[purchase.robot]);
+        let affordable = recipe.is_some_and(|recipe| {
+            recipe.costs.iter().zip(&resources).all(|(c, r)| c <= r)
+        });
+
+        if let Some(
+            purchase @ Purchase {
+                minute: Some(planned),
+                ..
+            },
+        ) = purchase
+        {
+            let name = blueprint.name(purchase.robot);
+            if planned < minute {
+                return Err(format!(
+                    "minute {planned}: too late to buy the {name} robot"
+                ));
+            }
+            if !affordable {
+                let missing = recipe
+                    .unwrap()
+                    .costs
+                    .iter()
+                    .zip(&resources)
+                    .zip(&blueprint.resources)
+                    .filter(|((cost, have), _)| cost > have)
+                    .map(|((cost, have), resource)| {
+                        format!("needs {cost} {resource} but there is {have}")
+                    })
+                    .collect::<Vec<_>>();
+                return Err(format!(
+                    "minute {minute}: cannot afford the {name} robot, {}",
+                    missing.join(", ")
+                ));
+            }
+        }
+
+        let bought_now = match (purchase, recipe) {
+            (Some(purchase), Some(recipe)) if affordable => {
+                for (resource, cost) in resources.iter_mut().zip(&recipe.costs)
+                {
+                    *resource -= cost;
+                }
+                Some(purchase.robot)
+            }
+            _ => None,
+        };
+
+        for (resource, robots) in resources.iter_mut().zip(&robots) {
+            *resource += robots;
+        }
+
+        if let Some(robot) = bought_now {
+            robots[blueprint.recipes[robot].resource] += 1;
+            buylist.next();
+            bought += 1;
+        }
+
+        record(minute, bought_now, &robots, &resources);
+    }
+
+    Ok((resources[blueprint.target()], bought))
+}
+
+/// How much of the target resource there is after `minutes`, if every
+/// minute the next robot of `buylist` is bought as soon as it is affordable.
+fn simulate(blueprint: &Blueprint, buylist: &[Robot], minutes: usize) -> i64 {
+    let buylist = buylist.iter().map(|&robot| Purchase {
+        robot,
+        minute: None,
+    });
+
+    replay(blueprint, buylist, minutes, |_, _, _, _| ())
+        .expect("purchases without a minute are never late")
+        .0
+}
+
+/// The state at the end of one minute of a [`Timeline`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Minute {
+    minute: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    built: Option<String>,
+    robots: Vec<i64>,
+    resources: Vec<i64>,
+}
+
+/// Everything that happens while a buylist is played through, minute by
+/// minute. Robots and resources are listed in the order of `resources`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Timeline {
+    blueprint: i64,
+    resources: Vec<String>,
+    minutes: Vec<Minute>,
+    target: String,
+    collected: i64,
+    /// The first purchase that could not be made as planned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl Timeline {
+    fn new(
+        blueprint: &Blueprint,
+        buylist: &[Purchase],
+        minutes: usize,
+    ) -> Self {
+        let mut timeline = Timeline {
+            blueprint: blueprint.id,
+            resources: blueprint.resources.clone(),
+            minutes: vec![],
+            target: blueprint.resources[blueprint.target()].clone(),
+            collected: 0,
+            error: None,
+        };
+
+        let result = replay(
+            blueprint,
+            buylist.iter().copied(),
+            minutes,
+            |minute, built, robots, resources| {
+                timeline.minutes.push(Minute {
+                    minute,
+                    built: built.map(|robot| blueprint.name(robot).to_owned()),
+                    robots: robots.to_vec(),
+                    resources: resources.to_vec(),
+                })
+            },
+        );
+
+        match result {
+            Ok((collected, bought)) => {
+                timeline.collected = collected;
+
+                // A robot that never became affordable is only noticed now.
+                if let Some(purchase) = buylist.get(bought) {
+                    timeline.error = Some(format!(
+                        "minute {minutes}: cannot afford the {} robot before \
+                         the time is up",
+                        blueprint.name(purchase.robot)
+                    ));
+                }
+            }
+            Err(error) => {
+                timeline.collected = timeline
+                    .minutes
+                    .last()
+                    .map_or(0, |minute| minute.resources[blueprint.target()]);
+                timeline.error = Some(error);
+            }
+        }
+
+        timeline
+    }
+
+    /// The timeline as a table with a column per robot and per resource.
+    fn table(&self) -> String {
+        let widths = self
+            .resources
+            .iter()
+            .map(|name| name.len().max(3))
+            .collect::<Vec<_>>();
+        let built = widths.iter().copied().max().unwrap_or(0).max(5);
+        let columns = |values: &[i64]| {
+            values
+                .iter()
+                .zip(&widths)
+                .map(|(value, &width)| format!("{value:>width$}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let names = self
+            .resources
+            .iter()
+            .zip(&widths)
+            .map(|(name, &width)| format!("{name:>width$}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let group = names.len();
+
+        let mut table = String::new();
+        writeln!(table, "Blueprint {}", self.blueprint).unwrap();
+        writeln!(
+            table,
+            "{:6}  {:built$}  {:<group$}  resources",
+            "", "", "robots"
+        )
+        .unwrap();
+        writeln!(
+            table,
+            "{:>6}  {:<built$}  {names}  {names}",
+            "minute", "built"
+        )
+        .unwrap();
+
+        for minute in &self.minutes {
+            writeln!(
+                table,
+                "{:>6}  {:<built$}  {}  {}",
+                minute.minute,
+                minute.built.as_deref().unwrap_or(""),
+                columns(&minute.robots),
+                columns(&minute.resources)
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            table,
+            "{} {} after {} minutes",
+            self.collected,
+            self.target,
+            self.minutes.len()
+        )
+        .unwrap();
+        if let Some(error) = &self.error {
+            writeln!(table, "error: {error}").unwrap();
+        }
+
+        table
+    }
+}
+
+/// Timelines for the blueprints, selected by options like those of
+/// [`Evolution::from_options`]: `blueprint` picks one, `minutes` defaults to
+/// 24 and `buy` is a buylist to check instead of the best one, e.g.
+/// `buy=clay clay@5 obsidian` to buy the second clay robot in minute 5. A
+/// `buy` needs a `blueprint` to check it against.
+fn timeline_report(
+    blueprints: &[Blueprint],
+    options: &str,
+    json: bool,
+) -> Result<String, String> {
+    let (mut id, mut minutes, mut buy): (Option<i64>, usize, _) =
+        (None, 24, None);
+
+    for option in options.split(',').filter(|o| !o.is_empty()) {
+        let invalid = || format!("invalid option `{option}`");
+
+        match option.split_once('=').ok_or_else(invalid)? {
+            ("blueprint", value) => {
+                id = Some(value.parse().map_err(|_| invalid())?)
+            }
+            ("minutes", value) => {
+                minutes = value.parse().map_err(|_| invalid())?
+            }
+            ("buy", value) => buy = Some(value),
+            (key, _) => return Err(format!("unknown option `{key}`")),
+        }
+    }
+
+    let blueprints = match id {
+        Some(id) => vec![blueprints
+            .iter()
+            .find(|b| b.id == id)
+            .ok_or_else(|| format!("no blueprint {id}"))?],
+        None if buy.is_some() => {
+            return Err("`buy` needs a `blueprint`".to_owned())
+        }
+        None => blueprints.iter().collect(),
+    };
+
+    let timelines = blueprints
+        .into_iter()
+        .map(|blueprint| {
+            let buylist = match buy {
+                Some(buy) => parse_buylist(blueprint, buy)?,
+                None => max_geodes(blueprint, minutes as i64)
+                    .1
+                    .into_iter()
+                    .map(|robot| Purchase {
+                        robot,
+                        minute: None,
+                    })
+                    .collect(),
+            };
+            Ok(Timeline::new(blueprint, &buylist, minutes))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(match json {
+        true => serde_json::to_string_pretty(&timelines).unwrap() + "\n",
+        false => timelines
+            .iter()
+            .map(Timeline::table)
+            .collect::<Vec<_>>()
+            .join("\n"),
+    })
+}
+
+/// Parses robot names separated by spaces, each optionally followed by `@`
+/// and the minute to buy it in.
+fn parse_buylist(
+    blueprint: &Blueprint,
+    buylist: &str,
+) -> Result<Vec<Purchase>, String> {
+    buylist
+        .split_whitespace()
+        .map(|purchase| {
+            let (name, minute) = match purchase.split_once('@') {
+                Some((name, minute)) => {
+                    let minute = minute.parse().map_err(|_| {
+                        format!("invalid minute in `{purchase}`")
+                    })?;
+                    (name, Some(minute))
+                }
+                None => (purchase, None),
+            };
+            let robot = blueprint.robot(name).ok_or_else(|| {
+                format!("blueprint {} has no `{name}` robot", blueprint.id)
+            })?;
+            Ok(Purchase { robot, minute })
+        })
+        .collect()
+}
+
+/// The robots and resources at some point of a search, with the robots
+/// bought so far.
+#[derive(Clone, Debug)]
+struct State {
+    time_left: i64,
+    robots: Vec<i64>,
+    resources: Vec<i64>,
+    bought: Vec<Robot>,
+}
+
+/// The most of the target resource `blueprint` can collect in `minutes`,
+/// along with a buylist for [`simulate`] that collects it.
+///
+/// Searches depth-first over which robot to buy next, waiting for as long as
+/// it takes to afford it. Branches are cut when they would build more robots
+/// of a kind than can be spent in a minute, or when even a new target robot
+/// every minute could not beat the best so far.
+fn max_geodes(blueprint: &Blueprint, minutes: i64) -> (i64, Vec<Robot>) {
+    let mut max_robots = vec![0; blueprint.resources.len()];
+    for recipe in &blueprint.recipes {
+        for (max, &cost) in max_robots.iter_mut().zip(&recipe.costs) {
+            *max = (*max).max(cost);
+        }
+    }
+    max_robots[blueprint.target()] = i64::MAX;
+
+    let mut state = State {
+        time_left: minutes,
+        robots: vec![0; blueprint.resources.len()],
+        resources: vec![0; blueprint.resources.len()],
+        bought: vec![],
+    };
+    state.robots[blueprint.recipes[0].resource] = 1;
+
+    let mut best = (0, vec![]);
+    search(blueprint, &max_robots, &mut state, &mut best);
+    debug_assert_eq!(simulate(blueprint, &best.1, minutes as usize), best.0);
+
+    best
+}
+
+fn search(
+    blueprint: &Blueprint,
+    max_robots: &[i64],
+    state: &mut State,
+    best: &mut (i64, Vec<Robot>),
+) {
+    let t = state.time_left;
+    let target = blueprint.target();
+    let collected = state.resources[target] + state.robots[target] * t;
+    if collected > best.0 {
+        *best = (collected, state.bought.clone());
+    }
+    if collected + t * (t - 1) / 2 <= best.0 {
+        return;
+    }
+
+    for (robot, recipe) in blueprint.recipes.iter().enumerate().rev() {
+        if state.robots[recipe.resource] >= max_robots[recipe.resource] {
+            continue;
+        }
+
+        // The minutes to wait until the robot is affordable.
+        let mut wait = 0;
+        for (resource, &cost) in recipe.costs.iter().enumerate() {
+            let missing = cost - state.resources[resource];
+            if missing > 0 {
+                match state.robots[resource] {
+                    0 => wait = t,
+                    robots => wait = wait.max((missing + robots - 1) / robots),
+                }
+            }
+        }
+        if wait + 1 >= t {
+            continue;
+        }
+
+        let step = |state: &mut State, sign: i64| {
+            for (resource, cost) in recipe.costs.iter().enumerate() {
+                let gained = state.robots[resource] * (wait + 1) - cost;
+                state.resources[resource] += sign * gained;
+            }
+            state.time_left -= sign * (wait + 1);
+        };
+
+        step(state, 1);
+        state.robots[recipe.resource] += 1;
+        state.bought.push(robot);
+
+        search(blueprint, max_robots, state, best);
+
+        state.bought.pop();
+        state.robots[recipe.resource] -= 1;
+        step(state, -1);
+    }
+}
+
+/// Settings of the genetic algorithm in [`evolve`], an inexact alternative
+/// to [`max_geodes`]. The same settings always give the same result, unless
+/// the time limit cuts a run short.
+#[derive(Debug, Clone, PartialEq)]
+struct Evolution {
+    seed: u64,
+    minutes: usize,
+    /// Buylists per generation.
+    population: usize,
+    /// The best buylists, kept as they are and bred from.
+    survivors: usize,
+    /// Random buylists added to every generation.
+    aliens: usize,
+    /// The chance of a random robot instead of a parent's.
+    mutation: f64,
+    generations: usize,
+    /// Stop after this many generations without a better buylist.
+    patience: usize,
+    time_limit: Option<Duration>,
+}
+
+impl Default for Evolution {
+    fn default() -> Self {
+        Evolution {
+            seed: 0,
+            minutes: 24,
+            population: 1000,
+            survivors: 50,
+            aliens: 10,
+            mutation: 0.4,
+            generations: 2000,
+            patience: 200,
+            time_limit: None,
+        }
+    }
+}
+
+impl Evolution {
+    /// Parses settings given as e.g. `seed=7,minutes=32`, any of them can be
+    /// left out. The time limit is in `seconds`.
+    fn from_options(options: &str) -> Result<Self, String> {
+        fn value<T: FromStr>(option: &str, value: &str) -> Result<T, String> {
+            value
+                .parse()
+                .map_err(|_| format!("invalid option `{option}`"))
+        }
+
+        let mut evolution = Evolution::default();
+
+        for option in options.split(',').filter(|o| !o.is_empty()) {
+            let (key, text) = option
+                .split_once('=')
+                .ok_or_else(|| format!("invalid option `{option}`"))?;
+
+            match key {
+                "seed" => evolution.seed = value(option, text)?,
+                "minutes" => evolution.minutes = value(option, text)?,
+                "population" => evolution.population = value(option, text)?,
+                "survivors" => evolution.survivors = value(option, text)?,
+                "aliens" => evolution.aliens = value(option, text)?,
+                "mutation" => evolution.mutation = value(option, text)?,
+                "generations" => evolution.generations = value(option, text)?,
+                "patience" => evolution.patience = value(option, text)?,
+                "seconds" => {
+                    let seconds = value(option, text)?;
+                    evolution.time_limit =
+                        Some(Duration::try_from_secs_f64(seconds).map_err(
+                            |_| format!("invalid option `{option}`"),
+                        )?);
+                }
+                _ => return Err(format!("unknown option `{key}`")),
+            }
+        }
+
+        match (0.0..=1.0).contains(&evolution.mutation) {
+            true => Ok(evolution),
+            false => Err("`mutation` has to be between 0 and 1".to_owned()),
+        }
+    }
+}
+
+/// The best buylist a run of [`evolve`] found, cut off after the last robot
+/// that is bought in time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Evolved {
+    geodes: i64,
+    buylist: Vec<Robot>,
+    generations: usize,
+}
+
+/// Breeds buylists for [`simulate`] until the generation or time budget is
+/// spent, or the best buylist stops improving.
+fn evolve(blueprint: &Blueprint, settings: &Evolution) -> Evolved {
+    let start = Instant::now();
+    let mut rng = SmallRng::seed_from_u64(settings.seed);
+    let minutes = settings.minutes;
+    let size = settings.population.max(1);
+    let survivors = settings.survivors.clamp(1, size);
+    let aliens = settings.aliens.min(size - survivors);
+
+    let robots = blueprint.recipes.len();
+    let random_buylist = |rng: &mut SmallRng| {
+        (0..minutes)
+            .map(|_| rng.gen_range(0..robots))
+            .collect::<Vec<_>>()
+    };
+
+    let mut population = (0..size)
+        .map(|_| {
+            let buylist = random_buylist(&mut rng);
+            (simulate(blueprint, &buylist, minutes), buylist)
+        })
+        .collect::<Vec<_>>();
+    population.sort_by_key(|individual| Reverse(individual.0));
+
+    let mut best = population[0].clone();
+    let (mut generations, mut stale) = (0, 0);
+
+    while generations < settings.generations
+        && stale < settings.patience
+        && settings
+            .time_limit
+            .is_none_or(|limit| start.elapsed() < limit)
+    {
+        for idx in survivors..size {
+            let buylist = if idx >= size - aliens {
+                random_buylist(&mut rng)
+            } else {
+                let left = rng.gen_range(0..survivors);
+                let right = rng.gen_range(0..survivors);
+
+                (0..minutes)
+                    .map(|minute| {
+                        if rng.gen_bool(settings.mutation) {
+                            rng.gen_range(0..robots)
+                        } else if rng.gen_bool(0.5) {
+                            population[left].1[minute]
+                        } else {
+                            population[right].1[minute]
+                        }
+                    })
+                    .collect()
+            };
+            population[idx] = (simulate(blueprint, &buylist, minutes), buylist);
+        }
+
+        population.sort_by_key(|individual| Reverse(individual.0));
+        generations += 1;
+
+        if population[0].0 > best.0 {
+            best = population[0].clone();
+            stale = 0;
+        } else {
+            stale += 1;
+        }
+    }
+
+    // Only the robots bought before the time is up belong to the buylist.
+    let purchases = best.1.iter().map(|&robot| Purchase {
+        robot,
+        minute: None,
+    });
+    let (_, bought) = replay(blueprint, purchases, minutes, |_, _, _, _| ())
+        .expect("purchases without a minute are never late");
+    best.1.truncate(bought);
+
+    Evolved {
+        geodes: best.0,
+        buylist: best.1,
+        generations,
+    }
+}
+
+/// A line per blueprint with the result of [`evolve`] next to the optimum.
+fn evolution_report(blueprints: &[Blueprint], settings: &Evolution) -> String {
+    let results = blueprints
+        .par_iter()
+        .map(|blueprint| {
+            let exact = max_geodes(blueprint, settings.minutes as i64).0;
+            (blueprint.id, evolve(blueprint, settings), exact)
+        })
+        .collect::<Vec<_>>();
+
+    let mut report = String::new();
+    for (blueprint, (id, evolved, exact)) in blueprints.iter().zip(results) {
+        let buylist = evolved
+            .buylist
+            .iter()
+            .map(|&robot| blueprint.name(robot))
+            .collect::<Vec<_>>()
+            .join(" ");
+        writeln!(
+            report,
+            "blueprint {id}: {} geodes (exact {exact}) after {} generations: \
+             {buylist}",
+            evolved.geodes, evolved.generations
+        )
+        .unwrap();
+    }
+
+    report
+}
+
+fn part1(blueprints: &[Blueprint]) -> i64 {
+    blueprints
+        .par_iter()
+        .map(|blueprint| blueprint.id * max_geodes(blueprint, 24).0)
+        .sum()
+}
+
+fn part2(blueprints: &[Blueprint]) -> i64 {
+    blueprints
+        .par_iter()
+        .take(3)
+        .map(|blueprint| max_geodes(blueprint, 32).0)
+        .product()
+}
+
+struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(input))
+    }
+
+    fn report(
+        input: &Self::Input,
+        name: &str,
+    ) -> Option<Result<String, String>> {
+        let (name, options) = name.split_once(',').unwrap_or((name, ""));
+
+        match name {
+            "evolve" => Some(
+                Evolution::from_options(options)
+                    .map(|settings| evolution_report(input, &settings)),
+            ),
+            "timeline" => Some(timeline_report(input, options, false)),
+            "timeline.json" => Some(timeline_report(input, options, true)),
+            _ => None,
+        }
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day19>;
+
+/// The most of the target resource in `minutes` by trying every choice in
+/// every minute, as a reference for [`max_geodes`].
+#[cfg(test)]
+fn max_geodes_brute_force(blueprint: &Blueprint, minutes: i64) -> i64 {
+    fn search(
+        blueprint: &Blueprint,
+        t: i64,
+        robots: &[i64],
+        res: &[i64],
+    ) -> i64 {
+        if t == 0 {
+            return res[blueprint.target()];
+        }
+
+        let collect = |res: &[i64]| {
+            res.iter()
+                .zip(robots)
+                .map(|(r, n)| r + n)
+                .collect::<Vec<_>>()
+        };
+        // Either buy nothing this minute, or one of the affordable robots.
+        let mut best = search(blueprint, t - 1, robots, &collect(res));
+
+        for recipe in &blueprint.recipes {
+            if recipe.costs.iter().zip(res).any(|(c, r)| c > r) {
+                continue;
+            }
+
+            let paid = res
+                .iter()
+                .zip(&recipe.costs)
+                .map(|(r, c)| r - c)
+                .collect::<Vec<_>>();
+            let mut robots = robots.to_vec();
+            let res = collect(&paid);
+            robots[recipe.resource] += 1;
+            best = best.max(search(blueprint, t - 1, &robots, &res));
+        }
+
+        best
+    }
+
+    let mut robots = vec![0; blueprint.resources.len()];
+    robots[blueprint.recipes[0].resource] = 1;
+    search(blueprint, minutes, &robots, &vec![0; robots.len()])
+}
+
+#[test]
+fn test_day19() {
+    let input = "\
+Blueprint 1: \
+  Each ore robot costs 4 ore. \
+  Each clay robot costs 2 ore. \
+  Each obsidian robot costs 3 ore and 14 clay. \
+  Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: \
+  Each ore robot costs 2 ore. \
+  Each clay robot costs 3 ore. \
+  Each obsidian robot costs 3 ore and 8 clay. \
+  Each geode robot costs 3 ore and 12 obsidian.";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 33);
+    assert_eq!(part2(&input), 56 * 62);
+    let (geodes, buylist) = max_geodes(&input[1], 32);
+    assert_eq!(simulate(&input[1], &buylist, 32), geodes);
+
+    // The plan from the puzzle, with every robot bought in the given minute.
+    let plan = "clay@3 clay@5 clay@7 obsidian@11 clay@12 obsidian@15 \
+                geode@18 geode@21";
+    let plan = parse_buylist(&input[0], plan).unwrap();
+    let timeline = Timeline::new(&input[0], &plan, 24);
+    assert_eq!((timeline.collected, &timeline.error), (9, &None));
+    assert_eq!(timeline.minutes[23].resources, [6, 41, 8, 9]);
+    let table = timeline.table();
+    assert_eq!(table.lines().nth(27), Some("9 geode after 24 minutes"));
+    let plan = parse_buylist(&input[0], "clay clay obsidian").unwrap();
+    assert_eq!(
+        Timeline::new(&input[0], &plan, 10).error.as_deref(),
+        Some(
+            "minute 10: cannot afford the obsidian robot before the time is up"
+        )
+    );
+    assert_eq!(
+        timeline_report(&input, "buy=clay", false),
+        Err("`buy` needs a `blueprint`".to_owned())
+    );
+
+    let settings = Evolution::from_options("seed=7,population=200").unwrap();
+    let evolved = evolve(&input[0], &settings);
+    assert!(evolved.geodes <= 9);
+    assert_eq!(simulate(&input[0], &evolved.buylist, 24), evolved.geodes);
+    assert_eq!(evolve(&input[0], &settings), evolved);
+    assert_eq!(
+        Evolution::from_options("mutation=2"),
+        Err("`mutation` has to be between 0 and 1".to_owned())
+    );
+
+    let input = "\
+Blueprint 7: Each ore robot costs 2 ore. Each sand robot costs 1 ore. \
+  Each glass robot costs 2 ore and 3 sand. \
+  Each lens robot costs 1 ore, 2 glass and 1 sand. \
+  Each gem robot costs 2 lens and 2 glass.
+Blueprint 8: Each ore robot costs 3 ore. Each clay robot costs 2 ore.";
+    let input = parse(input).unwrap();
+    assert_eq!(input[0].recipes[3].costs, [1, 1, 2, 0, 0]);
+    assert_eq!(input[1].resources[input[1].target()], "clay");
+
+    for blueprint in &input {
+        let best = max_geodes(blueprint, 13).0;
+        assert_eq!(best, max_geodes_brute_force(blueprint, 13));
+    }
+
+    let error = |input| parse(input).unwrap_err().message;
+    assert_eq!(
+        error("Blueprint 1: Each ore robot costs 1 ore and 2 ore."),
+        "duplicate cost"
+    );
+    assert_eq!(error("Blueprint 1: "), "no recipes");
+}
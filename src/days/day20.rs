@@ -0,0 +1,371 @@
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+};
+
+use crate::{
+    error::{parse_at, ParseError},
+    solution::{Solution, Solver},
+};
+
+fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    input.lines().map(|num| parse_at(input, num)).collect()
+}
+
+/// The order of the numbers while mixing, as their original indices. They
+/// are kept in blocks of about √n each, so that finding, removing and
+/// inserting an index all take O(√n).
+struct Order {
+    blocks: Vec<Vec<usize>>,
+    /// The block every index is in.
+    block_of: Vec<usize>,
+    block_size: usize,
+}
+
+impl Order {
+    fn new(len: usize) -> Self {
+        let mut order = Order {
+            blocks: vec![],
+            block_of: vec![0; len],
+            block_size: (len as f64).sqrt().ceil().max(1.0) as usize,
+        };
+        order.rebuild((0..len).collect());
+        order
+    }
+
+    /// Splits `indices` into evenly filled blocks again.
+    fn rebuild(&mut self, indices: Vec<usize>) {
+        self.blocks = indices
+            .chunks(self.block_size)
+            .map(<[usize]>::to_vec)
+            .collect();
+
+        for (block, indices) in self.blocks.iter().enumerate() {
+            for &idx in indices {
+                self.block_of[idx] = block;
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.block_of.len()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.blocks.iter().flatten().copied()
+    }
+
+    /// The block of `idx`, its position in the block and the number of
+    /// indices in the blocks before.
+    fn locate(&self, idx: usize) -> (usize, usize, usize) {
+        let block = self.block_of[idx];
+        let before = self.blocks[..block].iter().map(Vec::len).sum();
+        let within = self.blocks[block].iter().position(|&i| i == idx);
+
+        (block, within.unwrap(), before)
+    }
+
+    /// The current position of the number originally at `idx`.
+    fn position(&self, idx: usize) -> usize {
+        let (_, within, before) = self.locate(idx);
+        before + within
+    }
+
+    /// The original index of the number at `pos`.
+    fn get(&self, mut pos: usize) -> usize {
+        for block in &self.blocks {
+            if pos < block.len() {
+                return block[pos];
+            }
+            pos -= block.len();
+        }
+
+        panic!("position {pos} past the end");
+    }
+
+    /// Moves the number originally at `idx` by `offset` places through the
+    /// others, wrapping around at the ends.
+    fn shift(&mut self, idx: usize, offset: i64) {
+        let others = self.len() as i64 - 1;
+        if others <= 0 {
+            return;
+        }
+
+        let (block, within, before) = self.locate(idx);
+        self.blocks[block].remove(within);
+
+        let mut pos = (before as i64 + within as i64 + offset)
+            .rem_euclid(others) as usize;
+        let mut block = 0;
+        while pos > self.blocks[block].len() {
+            pos -= self.blocks[block].len();
+            block += 1;
+        }
+
+        self.blocks[block].insert(pos, idx);
+        self.block_of[idx] = block;
+
+        if self.blocks[block].len() > 2 * self.block_size {
+            self.rebuild(self.iter().collect());
+        }
+    }
+}
+
+/// Why the numbers around an anchor could not be looked up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AnchorError {
+    Missing(i64),
+    Ambiguous(i64, usize),
+}
+
+impl Display for AnchorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnchorError::Missing(anchor) => {
+                write!(f, "there is no {anchor} to count from")
+            }
+            AnchorError::Ambiguous(anchor, count) => {
+                write!(f, "there are {count} of {anchor} to count from")
+            }
+        }
+    }
+}
+
+/// Mixes a list of numbers after multiplying them with a decryption key.
+struct Mixer<'a> {
+    nums: &'a [i64],
+    key: i64,
+    order: Order,
+}
+
+impl<'a> Mixer<'a> {
+    fn new(nums: &'a [i64], key: i64) -> Self {
+        Mixer {
+            nums,
+            key,
+            order: Order::new(nums.len()),
+        }
+    }
+
+    /// Moves every number by its value, in their original order, `rounds`
+    /// times.
+    fn mix(&mut self, rounds: usize) {
+        for _ in 0..rounds {
+            for (idx, &num) in self.nums.iter().enumerate() {
+                self.order.shift(idx, num * self.key);
+            }
+        }
+    }
+
+    /// The numbers in their mixed order, multiplied with the key.
+    fn iter(&self) -> impl Iterator<Item = i64> + '_ {
+        self.order.iter().map(|idx| self.nums[idx] * self.key)
+    }
+
+    /// The numbers `offsets` places after `anchor`, which has to occur
+    /// exactly once among the numbers multiplied with the key. The list
+    /// wraps around, and negative offsets count backwards.
+    fn around(
+        &self,
+        anchor: i64,
+        offsets: &[i64],
+    ) -> Result<Vec<i64>, AnchorError> {
+        let mut matches = (0..self.nums.len())
+            .filter(|&idx| self.nums[idx] * self.key == anchor);
+        let idx = matches.next().ok_or(AnchorError::Missing(anchor))?;
+        let count = 1 + matches.count();
+        if count > 1 {
+            return Err(AnchorError::Ambiguous(anchor, count));
+        }
+
+        let pos = self.order.position(idx) as i64;
+        let len = self.order.len() as i64;
+
+        Ok(offsets
+            .iter()
+            .map(|offset| {
+                let pos = (pos + offset).rem_euclid(len) as usize;
+                self.nums[self.order.get(pos)] * self.key
+            })
+            .collect())
+    }
+}
+
+/// The sum of the grove coordinates after mixing.
+fn solve(nums: &[i64], key: i64, rounds: usize) -> Result<i64, AnchorError> {
+    let mut mixer = Mixer::new(nums, key);
+    mixer.mix(rounds);
+
+    Ok(mixer.around(0, &[1000, 2000, 3000])?.into_iter().sum())
+}
+
+/// The numbers around an anchor after mixing, selected by options like
+/// `key=811589153,rounds=10,anchor=0,offsets=1000 2000 3000`, which are also
+/// the defaults except for a key and rounds of 1. Prints a line per offset.
+fn around_report(nums: &[i64], options: &str) -> Result<String, String> {
+    let (mut key, mut rounds, mut anchor) = (1, 1, 0);
+    let mut offsets = vec![1000, 2000, 3000];
+
+    for option in options.split(',').filter(|o| !o.is_empty()) {
+        let invalid = || format!("invalid option `{option}`");
+
+        match option.split_once('=').ok_or_else(invalid)? {
+            ("key", value) => key = value.parse().map_err(|_| invalid())?,
+            ("rounds", value) => {
+                rounds = value.parse().map_err(|_| invalid())?
+            }
+            ("anchor", value) => {
+                anchor = value.parse().map_err(|_| invalid())?
+            }
+            ("offsets", value) => {
+                offsets = value
+                    .split_whitespace()
+                    .map(|offset| offset.parse().map_err(|_| invalid()))
+                    .collect::<Result<_, _>>()?
+            }
+            (key, _) => return Err(format!("unknown option `{key}`")),
+        }
+    }
+
+    let mut mixer = Mixer::new(nums, key);
+    mixer.mix(rounds);
+    let nums = mixer.around(anchor, &offsets).map_err(|e| e.to_string())?;
+
+    Ok(offsets
+        .iter()
+        .zip(nums)
+        .map(|(offset, num)| format!("{offset}: {num}\n"))
+        .collect())
+}
+
+struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        solve(input, 1, 1).map_err(|e| e.to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        solve(input, 811589153, 10).map_err(|e| e.to_string())
+    }
+
+    fn report(
+        input: &Self::Input,
+        name: &str,
+    ) -> Option<Result<String, String>> {
+        let (name, options) = name.split_once(',').unwrap_or((name, ""));
+        let (key, rounds) = match name {
+            "mixed1" => (1, 1),
+            "mixed2" => (811589153, 10),
+            "around" => return Some(around_report(input, options)),
+            _ => return None,
+        };
+        let mut mixer = Mixer::new(input, key);
+        mixer.mix(rounds);
+
+        Some(Ok(mixer.iter().map(|num| format!("{num}\n")).collect()))
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day20>;
+
+/// Mixing by removing and inserting into a plain `Vec`, which takes O(n²) per
+/// round, as a reference for [`Mixer`].
+#[cfg(test)]
+fn mix_brute_force(nums: &[i64], key: i64, rounds: usize) -> Vec<usize> {
+    let mut indices = (0..nums.len()).collect::<Vec<_>>();
+
+    for _ in 0..rounds {
+        for (idx, num) in nums.iter().map(|n| n * key).enumerate() {
+            if nums.len() < 2 {
+                break;
+            }
+            let old_pos = indices.iter().position(|&i| i == idx).unwrap();
+            indices.remove(old_pos);
+            let new_pos =
+                (old_pos as i64 + num).rem_euclid(indices.len() as i64);
+            indices.insert(new_pos as usize, idx);
+        }
+    }
+
+    indices
+}
+
+#[test]
+fn test_day20() {
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    let input = "\
+1
+2
+-3
+3
+-2
+0
+4";
+    let input = parse(input).unwrap();
+
+    assert_eq!(solve(&input, 1, 1), Ok(3));
+    assert_eq!(solve(&input, 811589153, 10), Ok(1623178306));
+    assert_eq!(
+        around_report(&input, "key=2,rounds=2,anchor=-6,offsets=0 -1"),
+        Ok("0: -6\n-1: 0\n".to_owned())
+    );
+    assert_eq!(
+        around_report(&input, "anchor=5"),
+        Err("there is no 5 to count from".to_owned())
+    );
+    assert_eq!(
+        around_report(&input, "offsets=1 x"),
+        Err("invalid option `offsets=1 x`".to_owned())
+    );
+
+    let mut mixer = Mixer::new(&input, 1);
+    mixer.mix(1);
+    assert_eq!(mixer.iter().collect::<Vec<_>>(), [-2, 1, 2, -3, 4, 0, 3]);
+    assert_eq!(mixer.around(0, &[1000, 2000, 3000]), Ok(vec![4, -3, 2]));
+    assert_eq!(mixer.around(2, &[0, 1, -1, -8]), Ok(vec![2, -3, 1, 1]));
+    assert_eq!(mixer.around(5, &[1]), Err(AnchorError::Missing(5)));
+
+    let mut mixer = Mixer::new(&input, 2);
+    mixer.mix(2);
+    assert_eq!(mixer.iter().filter(|n| n % 2 == 0).count(), 7);
+    assert_eq!(mixer.around(3, &[0]), Err(AnchorError::Missing(3)));
+    assert_eq!(mixer.around(-6, &[0]), Ok(vec![-6]));
+
+    let input = [0, 1, 0, 2];
+    assert_eq!(solve(&input, 1, 1), Err(AnchorError::Ambiguous(0, 2)));
+    let mixer = Mixer::new(&input, 1);
+    assert_eq!(mixer.around(0, &[1]), Err(AnchorError::Ambiguous(0, 2)));
+    assert_eq!(
+        AnchorError::Ambiguous(0, 2).to_string(),
+        "there are 2 of 0 to count from"
+    );
+    assert_eq!(Mixer::new(&[], 1).iter().count(), 0);
+
+    let mut rng = SmallRng::seed_from_u64(20);
+    for len in (0..40).chain([100, 257]) {
+        let nums = (0..len)
+            .map(|_| rng.gen_range(-3 * len - 5..=3 * len + 5))
+            .collect::<Vec<_>>();
+        let key = [1, -7, 811589153][len as usize % 3];
+        let rounds = len as usize % 4;
+
+        let mut mixer = Mixer::new(&nums, key);
+        mixer.mix(rounds);
+        let (order, expected) =
+            (&mixer.order, mix_brute_force(&nums, key, rounds));
+        assert_eq!(order.iter().collect::<Vec<_>>(), expected);
+        for (pos, &idx) in expected.iter().enumerate() {
+            assert_eq!((order.position(idx), order.get(pos)), (pos, idx));
+        }
+    }
+}
@@ -0,0 +1,578 @@
+use std::{fmt, marker::PhantomData};
+
+use ahash::AHashMap;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::Zero;
+
+use crate::{
+    error::{parse_at, ParseError},
+    solution::{Solution, Solver},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Op::Add => "+",
+            Op::Sub => "-",
+            Op::Mul => "*",
+            Op::Div => "/",
+        };
+        f.write_str(symbol)
+    }
+}
+
+type MonkeyId = usize;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Job {
+    Num(i64),
+    Op(Op, MonkeyId, MonkeyId),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Monkey {
+    name: String,
+    job: Job,
+}
+
+/// All monkeys, each stored once and referring to others by index, so a
+/// monkey that is listened to by several others is shared. Guaranteed to be
+/// free of cycles.
+#[derive(Debug, Clone)]
+struct Monkeys {
+    monkeys: Vec<Monkey>,
+    ids: AHashMap<String, MonkeyId>,
+    root: MonkeyId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum EvalError {
+    Overflow,
+    DivisionByZero,
+    /// The monkey where the unknown is multiplied with or divided by itself.
+    NotLinear(String),
+    MissingUnknown(String),
+    NoEquation,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::Overflow => write!(f, "overflows i64"),
+            EvalError::DivisionByZero => write!(f, "divides by zero"),
+            EvalError::NotLinear(name) => {
+                write!(f, "`{name}` isn't linear in the unknown")
+            }
+            EvalError::MissingUnknown(name) => {
+                write!(f, "doesn't depend on `{name}`")
+            }
+            EvalError::NoEquation => write!(f, "has no two sides to compare"),
+        }
+    }
+}
+
+impl Monkeys {
+    fn id(&self, name: &str) -> Option<MonkeyId> {
+        self.ids.get(name).copied()
+    }
+
+    /// The number `root` yells.
+    fn value(&self) -> Result<i64, EvalError> {
+        self.evaluate(self.root, &mut vec![None; self.monkeys.len()])
+    }
+
+    /// Evaluates monkey `id`, checking every operation for overflow. Every
+    /// monkey is only evaluated once, its result is kept in `memo`.
+    fn evaluate(
+        &self,
+        id: MonkeyId,
+        memo: &mut [Option<i64>],
+    ) -> Result<i64, EvalError> {
+        if let Some(value) = memo[id] {
+            return Ok(value);
+        }
+
+        let (op, l, r) = match self.monkeys[id].job {
+            Job::Num(n) => return Ok(n),
+            Job::Op(op, l, r) => {
+                (op, self.evaluate(l, memo)?, self.evaluate(r, memo)?)
+            }
+        };
+
+        let result = match op {
+            Op::Add => l.checked_add(r),
+            Op::Sub => l.checked_sub(r),
+            Op::Mul => l.checked_mul(r),
+            Op::Div if r == 0 => return Err(EvalError::DivisionByZero),
+            Op::Div => l.checked_div(r),
+        };
+
+        let value = result.ok_or(EvalError::Overflow)?;
+        memo[id] = Some(value);
+        Ok(value)
+    }
+
+    /// Rewrites monkey `id` as a linear function of `unknown`, whose own job
+    /// is ignored. Results are kept in `memo`.
+    fn linear(
+        &self,
+        id: MonkeyId,
+        unknown: MonkeyId,
+        memo: &mut [Option<Linear>],
+    ) -> Result<Linear, EvalError> {
+        if let Some(linear) = &memo[id] {
+            return Ok(linear.clone());
+        }
+
+        if id == unknown {
+            return Ok(Linear::unknown());
+        }
+
+        let (op, l, r) = match self.monkeys[id].job {
+            Job::Num(n) => return Ok(Linear::constant(n)),
+            Job::Op(op, l, r) => (
+                op,
+                self.linear(l, unknown, memo)?,
+                self.linear(r, unknown, memo)?,
+            ),
+        };
+        let not_linear = || EvalError::NotLinear(self.monkeys[id].name.clone());
+
+        let linear = match op {
+            Op::Add => Linear {
+                a: l.a + r.a,
+                b: l.b + r.b,
+            },
+            Op::Sub => Linear {
+                a: l.a - r.a,
+                b: l.b - r.b,
+            },
+            Op::Mul if l.is_constant() => r.scale(&l.b),
+            Op::Mul if r.is_constant() => l.scale(&r.b),
+            Op::Mul => return Err(not_linear()),
+            Op::Div if !r.is_constant() => return Err(not_linear()),
+            Op::Div if r.b.is_zero() => return Err(EvalError::DivisionByZero),
+            Op::Div => l.scale(&r.b.recip()),
+        };
+
+        memo[id] = Some(linear.clone());
+        Ok(linear)
+    }
+
+    /// Which monkeys depend on `target`, including `target` itself.
+    fn depends_on(&self, target: MonkeyId) -> Vec<bool> {
+        fn visit(
+            monkeys: &Monkeys,
+            id: MonkeyId,
+            target: MonkeyId,
+            memo: &mut [Option<bool>],
+        ) -> bool {
+            if let Some(depends) = memo[id] {
+                return depends;
+            }
+
+            let depends = id == target
+                || match monkeys.monkeys[id].job {
+                    Job::Num(_) => false,
+                    Job::Op(_, l, r) => {
+                        // no short circuit, so both sides end up in `memo`
+                        visit(monkeys, l, target, memo)
+                            | visit(monkeys, r, target, memo)
+                    }
+                };
+
+            memo[id] = Some(depends);
+            depends
+        }
+
+        let mut memo = vec![None; self.monkeys.len()];
+        for id in 0..self.monkeys.len() {
+            visit(self, id, target, &mut memo);
+        }
+
+        memo.into_iter().map(|depends| depends.unwrap()).collect()
+    }
+
+    /// The graph in Graphviz DOT format, with every monkey pointing to the
+    /// monkeys it listens to. The monkeys that depend on `highlight` and the
+    /// edges between them are drawn in red.
+    fn to_dot(&self, highlight: &str) -> String {
+        let depends = match self.id(highlight) {
+            Some(id) => self.depends_on(id),
+            None => vec![false; self.monkeys.len()],
+        };
+        let mut dot = "digraph monkeys {\n".to_owned();
+
+        for (id, monkey) in self.monkeys.iter().enumerate() {
+            let name = &monkey.name;
+            let job = match monkey.job {
+                Job::Num(n) => n.to_string(),
+                Job::Op(op, _, _) => op.to_string(),
+            };
+            let style = if depends[id] {
+                ", color=red, penwidth=2"
+            } else {
+                ""
+            };
+            dot +=
+                &format!("    \"{name}\" [label=\"{name}\\n{job}\"{style}];\n");
+
+            if let Job::Op(_, l, r) = monkey.job {
+                for operand in [l, r] {
+                    let style = match depends[operand] {
+                        true => " [color=red, penwidth=2]",
+                        false => "",
+                    };
+                    let operand = &self.monkeys[operand].name;
+                    dot +=
+                        &format!("    \"{name}\" -> \"{operand}\"{style};\n");
+                }
+            }
+        }
+
+        dot + "}\n"
+    }
+}
+
+/// `a·x + b` with exact coefficients.
+#[derive(Debug, Clone, PartialEq)]
+struct Linear {
+    a: BigRational,
+    b: BigRational,
+}
+
+impl Linear {
+    fn constant(n: i64) -> Self {
+        Linear {
+            a: BigRational::zero(),
+            b: BigRational::from_integer(n.into()),
+        }
+    }
+
+    fn unknown() -> Self {
+        Linear {
+            a: BigRational::from_integer(1.into()),
+            b: BigRational::zero(),
+        }
+    }
+
+    fn is_constant(&self) -> bool {
+        self.a.is_zero()
+    }
+
+    fn scale(self, factor: &BigRational) -> Self {
+        Linear {
+            a: self.a * factor,
+            b: self.b * factor,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Answer {
+    Value(BigInt),
+    NoIntegerSolution,
+    NoSolution,
+    InfinitelyMany,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Value(value) => write!(f, "{value}"),
+            Answer::NoIntegerSolution => write!(f, "no integer solution"),
+            Answer::NoSolution => write!(f, "no solution"),
+            Answer::InfinitelyMany => write!(f, "infinitely many"),
+        }
+    }
+}
+
+/// Finds the number the monkey named `unknown` has to yell so that both
+/// operands of `root` are equal, with exact rational arithmetic.
+fn solve(monkeys: &Monkeys, unknown: &str) -> Result<Answer, EvalError> {
+    let missing = || EvalError::MissingUnknown(unknown.to_owned());
+    let unknown = monkeys.id(unknown).ok_or_else(missing)?;
+    let Job::Op(_, left, right) = monkeys.monkeys[monkeys.root].job else {
+        return Err(EvalError::NoEquation);
+    };
+
+    // a·x + b = c·x + d  <=>  (a - c)·x = d - b
+    let mut memo = vec![None; monkeys.monkeys.len()];
+    let left = monkeys.linear(left, unknown, &mut memo)?;
+    let right = monkeys.linear(right, unknown, &mut memo)?;
+    let factor = left.a - right.a;
+    let rest = right.b - left.b;
+
+    if factor.is_zero() {
+        if !monkeys.depends_on(unknown)[monkeys.root] {
+            return Err(missing());
+        }
+
+        return Ok(match rest.is_zero() {
+            true => Answer::InfinitelyMany,
+            false => Answer::NoSolution,
+        });
+    }
+
+    let x = rest / factor;
+    Ok(match x.is_integer() {
+        true => Answer::Value(x.to_integer()),
+        false => Answer::NoIntegerSolution,
+    })
+}
+
+/// Checks that no monkey (indirectly) listens to itself.
+fn check_cycles(
+    input: &str,
+    names: &[&str],
+    monkeys: &[Monkey],
+) -> Result<(), ParseError> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        New,
+        Active,
+        Done,
+    }
+
+    fn visit(
+        id: MonkeyId,
+        monkeys: &[Monkey],
+        state: &mut [State],
+        path: &mut Vec<MonkeyId>,
+    ) -> Result<(), Vec<MonkeyId>> {
+        match state[id] {
+            State::Done => return Ok(()),
+            State::Active => {
+                let start = path.iter().position(|&p| p == id).unwrap();
+                return Err([&path[start..], &[id]].concat());
+            }
+            State::New => (),
+        }
+
+        state[id] = State::Active;
+        path.push(id);
+
+        if let Job::Op(_, l, r) = monkeys[id].job {
+            visit(l, monkeys, state, path)?;
+            visit(r, monkeys, state, path)?;
+        }
+
+        path.pop();
+        state[id] = State::Done;
+        Ok(())
+    }
+
+    let mut state = vec![State::New; monkeys.len()];
+
+    for id in 0..monkeys.len() {
+        if let Err(cycle) = visit(id, monkeys, &mut state, &mut vec![]) {
+            let names = cycle.iter().map(|&id| names[id]).collect::<Vec<_>>();
+            return Err(ParseError::at(
+                input,
+                names[0],
+                format!("cycle {}", names.join(" -> ")),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn parse(input: &str) -> Result<Monkeys, ParseError> {
+    let mut ids = AHashMap::default();
+    let mut names = vec![];
+    let mut jobs = vec![];
+
+    for line in input.lines() {
+        let Some((name, job)) = line.split_once(':') else {
+            return Err(ParseError::at(input, line, "expected `name: job`"));
+        };
+
+        let job = job.split_ascii_whitespace().collect::<Vec<_>>();
+
+        if ids.insert(name.to_owned(), names.len()).is_some() {
+            return Err(ParseError::at(input, name, "duplicate monkey"));
+        }
+
+        names.push(name);
+        jobs.push((line, job));
+    }
+
+    let id = |name: &str| match ids.get(name) {
+        Some(&id) => Ok(id),
+        None => Err(ParseError::at(input, name, "unknown monkey")),
+    };
+
+    let monkeys = names
+        .iter()
+        .zip(&jobs)
+        .map(|(name, (line, job))| {
+            let job = match job.as_slice() {
+                [l, op, r] => {
+                    let op = match *op {
+                        "+" => Op::Add,
+                        "-" => Op::Sub,
+                        "*" => Op::Mul,
+                        "/" => Op::Div,
+                        _ => {
+                            return Err(ParseError::at(
+                                input,
+                                op,
+                                "invalid operator",
+                            ))
+                        }
+                    };
+                    Job::Op(op, id(l)?, id(r)?)
+                }
+                [n] => Job::Num(parse_at(input, n)?),
+                _ => return Err(ParseError::at(input, line, "invalid job")),
+            };
+
+            Ok(Monkey {
+                name: name.to_string(),
+                job,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    check_cycles(input, &names, &monkeys)?;
+
+    let Some(&root) = ids.get("root") else {
+        return Err(ParseError::after(input, input, "missing `root`"));
+    };
+
+    Ok(Monkeys { monkeys, ids, root })
+}
+
+fn part1(monkeys: &Monkeys) -> Result<i64, String> {
+    monkeys.value().map_err(|e| format!("`root` {e}"))
+}
+
+fn part2(monkeys: &Monkeys) -> Result<Answer, String> {
+    solve(monkeys, "humn").map_err(|e| format!("`root` {e}"))
+}
+
+struct Day21;
+
+impl Solution for Day21 {
+    type Input = Monkeys;
+    type Part1 = i64;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+
+    fn report(
+        input: &Self::Input,
+        name: &str,
+    ) -> Option<Result<String, String>> {
+        match name {
+            "dot" => Some(Ok(input.to_dot("humn"))),
+            _ => None,
+        }
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day21>;
+
+#[test]
+fn test_day21() {
+    let input = "\
+root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+    let input = parse(input).unwrap();
+
+    assert_eq!(input.monkeys.len(), 15);
+    assert_eq!(part1(&input), Ok(152));
+    assert_eq!(part2(&input), Ok(Answer::Value(301.into())));
+    assert_eq!(solve(&input, "sllz"), Ok(Answer::Value(596.into())));
+    assert_eq!(solve(&input, "cczh"), Ok(Answer::Value(600.into())));
+    assert_eq!(solve(&input, "dbpl"), Ok(Answer::NoIntegerSolution));
+    assert_eq!(
+        solve(&input, "nobody"),
+        Err(EvalError::MissingUnknown("nobody".to_owned()))
+    );
+
+    let input = parse("root: a * b\na: 4611686018427387904\nb: 2").unwrap();
+    assert_eq!(input.value(), Err(EvalError::Overflow));
+    let input = parse("root: a / humn\na: 4\nhumn: 0").unwrap();
+    assert_eq!(input.value(), Err(EvalError::DivisionByZero));
+    assert_eq!(part1(&input), Err("`root` divides by zero".to_owned()));
+
+    // x / 2 * 4 = x + x
+    let input = "root: a + b\na: c * e\nc: x / f\ne: 4\nf: 2\nb: x + x\nx: 3";
+    let input = parse(input).unwrap();
+    assert_eq!(solve(&input, "x"), Ok(Answer::InfinitelyMany));
+    assert_eq!(solve(&input, "e"), Ok(Answer::Value(4.into())));
+    assert_eq!(solve(&input, "f"), Err(EvalError::NotLinear("c".into())));
+
+    // x + x = (x + 1) + x
+    let input = "root: a + b\na: x + x\nb: c + x\nc: x + e\ne: 1\nx: 0";
+    let input = parse(input).unwrap();
+    assert_eq!(solve(&input, "x"), Ok(Answer::NoSolution));
+
+    let input = parse("root: a + b\na: x * x\nb: 4\nx: 0").unwrap();
+    assert_eq!(
+        solve(&input, "x"),
+        Err(EvalError::NotLinear("a".to_owned()))
+    );
+    let input = parse("root: 5").unwrap();
+    assert_eq!(solve(&input, "root"), Err(EvalError::NoEquation));
+    let input = parse("root: a + b\na: 1\nb: 2\nhumn: 3").unwrap();
+    assert_eq!(
+        part2(&input),
+        Err("`root` doesn't depend on `humn`".to_owned())
+    );
+
+    let input = "root: a + b\na: b * c\nb: 3\nc: humn - b\nhumn: 1";
+    let dot = parse(input).unwrap().to_dot("humn");
+    assert!(dot.starts_with("digraph monkeys {\n"));
+    assert!(dot.contains("    \"root\" [label=\"root\\n+\", color=red"));
+    assert!(dot.contains("    \"b\" [label=\"b\\n3\"];"));
+    assert!(dot.contains("    \"a\" -> \"b\";"));
+    assert!(dot.contains("    \"a\" -> \"c\" [color=red, penwidth=2];"));
+    assert_eq!(dot.matches("->").count(), 6);
+
+    let input = "root: a + b\na: b * c\nb: 3\nc: humn - a\nhumn: 1";
+    let err = parse(input).unwrap_err();
+    assert_eq!((err.line, err.message.as_str()), (2, "cycle a -> c -> a"));
+    let err = parse("root: a + b\na: 1").unwrap_err();
+    assert_eq!((err.column, err.message.as_str()), (11, "unknown monkey"));
+    let err = parse("root: a + a\na: 1\na: 2").unwrap_err();
+    assert_eq!((err.line, err.message.as_str()), (3, "duplicate monkey"));
+    let err = parse("root: a % b\na: 1\nb: 2").unwrap_err();
+    assert_eq!((err.column, err.message.as_str()), (9, "invalid operator"));
+    let err = parse("root: a + b c\na: 1\nb: 2").unwrap_err();
+    assert_eq!((err.line, err.message.as_str()), (1, "invalid job"));
+}
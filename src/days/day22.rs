@@ -0,0 +1,695 @@
+use std::{fmt, marker::PhantomData};
+
+use ahash::AHashMap;
+
+use crate::{
+    error::{parse_at, ParseError},
+    grid::{Grid, GridView, Pos},
+    scan::{self, Piece},
+    solution::{Solution, Solver},
+    visual::{Frame, Recorder},
+};
+
+#[derive(Debug)]
+enum Instruction {
+    Left,
+    Right,
+    Forward(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Void,
+    Open,
+    Wall,
+}
+
+/// Where walking off the map leads to, for every tile and facing: the tile
+/// and facing after the step, if that step leaves the map.
+type Wraps = Grid<[Option<(Pos, usize)>; 4]>;
+
+#[derive(Debug)]
+struct Field {
+    tiles: Grid<Tile>,
+    /// The first and last x of the tiles in every row.
+    rows: Vec<(i64, i64)>,
+    /// The first and last y of the tiles in every column.
+    columns: Vec<(i64, i64)>,
+}
+
+/// The four directions in the order of their facing value.
+const DIRS: [(i64, i64); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+const FACING_CHARS: [char; 4] = ['>', 'v', '<', '^'];
+const EDGES: [&str; 4] = ["right", "bottom", "left", "top"];
+
+/// A step over the edge of the map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Warp {
+    instruction: usize,
+    from: (Pos, usize),
+    to: (Pos, usize),
+}
+
+impl fmt::Display for Warp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ((from, from_facing), (to, to_facing)) = (self.from, self.to);
+
+        write!(
+            f,
+            "instruction {}: {} edge of row {} column {} -> {} edge of row {} \
+             column {}, facing {}",
+            self.instruction + 1,
+            EDGES[from_facing],
+            from.1 + 1,
+            from.0 + 1,
+            EDGES[(to_facing + 2) % 4],
+            to.1 + 1,
+            to.0 + 1,
+            FACING_CHARS[to_facing]
+        )
+    }
+}
+
+/// The path taken by [`solve`].
+#[derive(Debug)]
+struct Trace {
+    /// The facing every visited tile was last seen with.
+    facings: Grid<Option<usize>>,
+    warps: Vec<Warp>,
+    instruction: usize,
+}
+
+impl Trace {
+    fn new(field: &Field) -> Self {
+        Trace {
+            facings: field.tiles.map(|_| None),
+            warps: vec![],
+            instruction: 0,
+        }
+    }
+
+    /// The map with the path drawn as `>v<^`.
+    fn render(&self, field: &Field) -> String {
+        field.tiles.render(|pos, _| field.char_at(pos, self))
+    }
+
+    /// One line per warp.
+    fn warp_log(&self) -> String {
+        self.warps.iter().map(|warp| format!("{warp}\n")).collect()
+    }
+}
+
+fn ahead((x, y): Pos, facing: usize) -> Pos {
+    let (dx, dy) = DIRS[facing];
+    (x + dx, y + dy)
+}
+
+impl Field {
+    fn new(tiles: Grid<Tile>) -> Self {
+        let mut rows = vec![(i64::MAX, i64::MIN); tiles.height()];
+        let mut columns = vec![(i64::MAX, i64::MIN); tiles.width()];
+
+        for ((x, y), &tile) in tiles.iter() {
+            if tile != Tile::Void {
+                let row = &mut rows[y as usize];
+                *row = (row.0.min(x), row.1.max(x));
+                let column = &mut columns[x as usize];
+                *column = (column.0.min(y), column.1.max(y));
+            }
+        }
+
+        Field {
+            tiles,
+            rows,
+            columns,
+        }
+    }
+
+    fn tile(&self, pos: Pos) -> Tile {
+        self.tiles.get(pos).copied().unwrap_or(Tile::Void)
+    }
+
+    /// The tiles that are part of the map.
+    fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.tiles
+            .iter()
+            .filter(|(_, &tile)| tile != Tile::Void)
+            .map(|(pos, _)| pos)
+    }
+
+    /// The character for `pos` on the map, with the facing of the last
+    /// visit for the tiles in `trace`.
+    fn char_at(&self, pos: Pos, trace: &Trace) -> char {
+        match (self.tile(pos), trace.facings[pos]) {
+            (Tile::Void, _) => ' ',
+            (Tile::Wall, _) => '#',
+            (Tile::Open, Some(facing)) => FACING_CHARS[facing],
+            (Tile::Open, None) => '.',
+        }
+    }
+
+    /// Draws the board with the path so far and the current position as `@`.
+    fn frame(&self, pos: Pos, trace: &Trace) -> Frame {
+        let bounds = self.tiles.bounds().unwrap();
+
+        Frame::render(&self.tiles, bounds, |p, _| match p == pos {
+            true => '@',
+            false => self.char_at(p, trace),
+        })
+    }
+
+    /// Builds the wrap table from `wrap`, which is called for every step
+    /// that leaves the map.
+    fn wraps(&self, mut wrap: impl FnMut(Pos, usize) -> (Pos, usize)) -> Wraps {
+        let mut wraps = self.tiles.map(|_| [None; 4]);
+
+        for pos in self.positions() {
+            for facing in 0..4 {
+                if self.tile(ahead(pos, facing)) == Tile::Void {
+                    wraps[pos][facing] = Some(wrap(pos, facing));
+                }
+            }
+        }
+
+        wraps
+    }
+
+    /// Wraps around to the other end of the row or column.
+    fn flat_wraps(&self) -> Wraps {
+        self.wraps(|(x, y), facing| {
+            let (row, column) =
+                (self.rows[y as usize], self.columns[x as usize]);
+            let pos = match facing {
+                0 => (row.0, y),
+                1 => (x, column.0),
+                2 => (row.1, y),
+                _ => (x, column.1),
+            };
+            (pos, facing)
+        })
+    }
+}
+
+fn parse(input: &str) -> Result<(Field, Vec<Instruction>), ParseError> {
+    let mut ins = vec![];
+
+    let mut lines = input.lines();
+    let map = lines.by_ref().take_while(|line| !line.is_empty());
+    let map = map.collect::<Vec<_>>();
+    let width = map.iter().map(|line| line.chars().count()).max();
+    let mut tiles = Grid::new(width.unwrap_or(0), map.len(), Tile::Void);
+
+    for (y, line) in map.iter().enumerate() {
+        for (x, (offset, c)) in line.char_indices().enumerate() {
+            tiles[(x as i64, y as i64)] = match c {
+                ' ' => Tile::Void,
+                '.' => Tile::Open,
+                '#' => Tile::Wall,
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        &line[offset..offset + c.len_utf8()],
+                        "invalid tile",
+                    ));
+                }
+            };
+        }
+    }
+
+    if tiles.iter().all(|(_, &tile)| tile == Tile::Void) {
+        return Err(ParseError::at(input, input, "missing map"));
+    }
+
+    let Some(path) = lines.next() else {
+        return Err(ParseError::after(input, input, "missing path"));
+    };
+
+    for piece in scan::pieces(path) {
+        let text = match piece {
+            Piece::Number(by) => {
+                ins.push(Instruction::Forward(parse_at(input, by)?));
+                continue;
+            }
+            Piece::Text(text) => text,
+        };
+
+        for (idx, c) in text.char_indices() {
+            ins.push(match c {
+                'L' => Instruction::Left,
+                'R' => Instruction::Right,
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        &text[idx..idx + c.len_utf8()],
+                        "invalid instruction",
+                    ))
+                }
+            })
+        }
+    }
+
+    Ok((Field::new(tiles), ins))
+}
+
+fn part1(input: &(Field, Vec<Instruction>)) -> i64 {
+    solve(input, &input.0.flat_wraps(), None, &mut Recorder::off())
+}
+
+fn step(
+    pos: &mut Pos,
+    facing: &mut usize,
+    by: i64,
+    field: &Field,
+    wraps: &Wraps,
+    mut trace: Option<&mut Trace>,
+) {
+    for _ in 0..by {
+        let warps = field.tile(ahead(*pos, *facing)) == Tile::Void;
+        let (next_pos, next_facing) = match warps {
+            true => wraps[*pos][*facing].unwrap(),
+            false => (ahead(*pos, *facing), *facing),
+        };
+
+        if field.tile(next_pos) == Tile::Wall {
+            return;
+        }
+
+        if let Some(trace) = trace.as_deref_mut() {
+            if warps {
+                trace.warps.push(Warp {
+                    instruction: trace.instruction,
+                    from: (*pos, *facing),
+                    to: (next_pos, next_facing),
+                });
+            }
+            trace.facings[next_pos] = Some(next_facing);
+        }
+
+        *pos = next_pos;
+        *facing = next_facing;
+    }
+}
+
+fn solve(
+    (field, instructions): &(Field, Vec<Instruction>),
+    wraps: &Wraps,
+    mut trace: Option<&mut Trace>,
+    recorder: &mut Recorder,
+) -> i64 {
+    let mut pos = (field.rows[0].0, 0);
+    let mut facing = 0;
+
+    if let Some(trace) = trace.as_deref_mut() {
+        trace.facings[pos] = Some(facing);
+    }
+
+    for (idx, ins) in instructions.iter().enumerate() {
+        match ins {
+            Instruction::Left => facing = (facing + 3) % 4,
+            Instruction::Right => facing = (facing + 1) % 4,
+            Instruction::Forward(by) => {
+                if let Some(trace) = trace.as_deref_mut() {
+                    trace.instruction = idx;
+                }
+                step(
+                    &mut pos,
+                    &mut facing,
+                    *by,
+                    field,
+                    wraps,
+                    trace.as_deref_mut(),
+                );
+            }
+        }
+
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.facings[pos] = Some(facing);
+            recorder.frame(|| field.frame(pos, trace));
+        }
+    }
+
+    1000 * (pos.1 + 1) + 4 * (pos.0 + 1) + facing as i64
+}
+
+type Vec3 = [i64; 3];
+
+fn neg([x, y, z]: Vec3) -> Vec3 {
+    [-x, -y, -z]
+}
+
+/// The orientation of a face once the net is folded: where its right and
+/// down directions and its outward normal point to in 3D.
+#[derive(Debug, Clone, Copy)]
+struct Orientation {
+    right: Vec3,
+    down: Vec3,
+    normal: Vec3,
+}
+
+impl Orientation {
+    /// The orientation of the face next to this one in direction `facing`
+    /// of the net, after folding it along their common edge.
+    fn roll(self, facing: usize) -> Self {
+        let Orientation {
+            right,
+            down,
+            normal,
+        } = self;
+
+        match facing {
+            0 => Orientation {
+                right: neg(normal),
+                down,
+                normal: right,
+            },
+            1 => Orientation {
+                right,
+                down: neg(normal),
+                normal: down,
+            },
+            2 => Orientation {
+                right: normal,
+                down,
+                normal: neg(right),
+            },
+            _ => Orientation {
+                right,
+                down: normal,
+                normal: neg(down),
+            },
+        }
+    }
+
+    /// The 3D direction of the edge in direction `facing`.
+    fn towards(self, facing: usize) -> Vec3 {
+        [self.right, self.down, neg(self.right), neg(self.down)][facing]
+    }
+}
+
+/// The map folded into a cube.
+#[derive(Debug)]
+struct Cube {
+    size: i64,
+    /// The position of every face in the net, in units of `size`.
+    faces: Vec<(i64, i64)>,
+    face_at: AHashMap<(i64, i64), usize>,
+    /// For every face and facing: the face behind that edge and the facing
+    /// of its edge that leads back.
+    edges: Vec<[(usize, usize); 4]>,
+}
+
+impl Cube {
+    /// Detects the face size and the six faces of the net and folds them
+    /// into a cube. Fails if the map isn't a cube net.
+    fn fold(field: &Field) -> Result<Self, &'static str> {
+        let tiles = field.positions().count() as i64;
+        let size = (1..).find(|n| 6 * n * n >= tiles).unwrap();
+        if 6 * size * size != tiles {
+            return Err("the map doesn't have six square faces");
+        }
+
+        let mut faces = vec![];
+        let mut face_at = AHashMap::default();
+        for y in (0..field.tiles.height() as i64).step_by(size as usize) {
+            for x in (0..field.tiles.width() as i64).step_by(size as usize) {
+                if field.tile((x, y)) != Tile::Void {
+                    let face = (x / size, y / size);
+                    face_at.insert(face, faces.len());
+                    faces.push(face);
+                }
+            }
+        }
+        let on_face = |(x, y): Pos| face_at.contains_key(&(x / size, y / size));
+        if faces.len() != 6 || !field.positions().all(on_face) {
+            return Err("the faces don't line up");
+        }
+
+        let mut orientations = vec![None; 6];
+        let mut queue = vec![0];
+        orientations[0] = Some(Orientation {
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+            normal: [0, 0, 1],
+        });
+
+        while let Some(face) = queue.pop() {
+            let (x, y) = faces[face];
+            let orientation = orientations[face].unwrap();
+
+            for (facing, (dx, dy)) in DIRS.into_iter().enumerate() {
+                if let Some(&next) = face_at.get(&(x + dx, y + dy)) {
+                    if orientations[next].is_none() {
+                        orientations[next] = Some(orientation.roll(facing));
+                        queue.push(next);
+                    }
+                }
+            }
+        }
+
+        let Some(orientations) =
+            orientations.into_iter().collect::<Option<Vec<_>>>()
+        else {
+            return Err("the faces aren't connected");
+        };
+
+        let mut edges = vec![];
+        for from in &orientations {
+            let mut face_edges = [(0, 0); 4];
+
+            for (facing, edge) in face_edges.iter_mut().enumerate() {
+                let towards = from.towards(facing);
+                let Some(to) =
+                    orientations.iter().position(|o| o.normal == towards)
+                else {
+                    return Err("two faces overlap");
+                };
+                let back = (0..4)
+                    .find(|&f| orientations[to].towards(f) == from.normal)
+                    .unwrap();
+                *edge = (to, back);
+            }
+
+            edges.push(face_edges);
+        }
+
+        Ok(Cube {
+            size,
+            faces,
+            face_at,
+            edges,
+        })
+    }
+
+    /// Moves `(x, y)`, which is on the edge of a face, over that edge in
+    /// direction `facing` onto the adjacent face.
+    fn warp(&self, (x, y): Pos, facing: usize) -> (Pos, usize) {
+        let n = self.size;
+        let face = self.face_at[&(x / n, y / n)];
+        let (lx, ly) = (x % n, y % n);
+
+        // Offsets along the edges clockwise, which is the opposite direction
+        // on the two faces sharing an edge.
+        let offset = match facing {
+            0 => ly,
+            1 => n - 1 - lx,
+            2 => n - 1 - ly,
+            _ => lx,
+        };
+        let (to, back) = self.edges[face][facing];
+        let offset = n - 1 - offset;
+        let (lx, ly) = match back {
+            0 => (n - 1, offset),
+            1 => (n - 1 - offset, n - 1),
+            2 => (0, n - 1 - offset),
+            _ => (offset, 0),
+        };
+
+        let (fx, fy) = self.faces[to];
+        ((fx * n + lx, fy * n + ly), (back + 2) % 4)
+    }
+}
+
+/// The wrap table of the map folded into a cube.
+fn cube_wraps(field: &Field) -> Result<Wraps, String> {
+    let cube = Cube::fold(field).map_err(|e| format!("no cube, {e}"))?;
+    Ok(field.wraps(|pos, facing| cube.warp(pos, facing)))
+}
+
+fn part2(input: &(Field, Vec<Instruction>)) -> Result<i64, String> {
+    let wraps = cube_wraps(&input.0)?;
+    Ok(solve(input, &wraps, None, &mut Recorder::off()))
+}
+
+/// Walks the path of `part` and keeps track of it.
+fn trace(
+    input: &(Field, Vec<Instruction>),
+    part: u8,
+    recorder: &mut Recorder,
+) -> Result<Trace, String> {
+    let field = &input.0;
+    let wraps = match part {
+        1 => field.flat_wraps(),
+        _ => cube_wraps(field)?,
+    };
+    let mut trace = Trace::new(field);
+
+    solve(input, &wraps, Some(&mut trace), recorder);
+    Ok(trace)
+}
+
+struct Day22;
+
+impl Solution for Day22 {
+    type Input = (Field, Vec<Instruction>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+
+    fn visualise(
+        input: &Self::Input,
+        part: u8,
+        recorder: &mut Recorder,
+    ) -> Result<bool, String> {
+        trace(input, part, recorder)?;
+        Ok(true)
+    }
+
+    fn report(
+        input: &Self::Input,
+        name: &str,
+    ) -> Option<Result<String, String>> {
+        let (part, warps) = match name {
+            "trace1" => (1, false),
+            "trace2" => (2, false),
+            "warps1" => (1, true),
+            "warps2" => (2, true),
+            _ => return None,
+        };
+        let trace = trace(input, part, &mut Recorder::off());
+
+        Some(trace.map(|trace| match warps {
+            true => trace.warp_log(),
+            false => trace.render(&input.0),
+        }))
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day22>;
+
+#[test]
+fn test_day22() {
+    let input = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 6032);
+    assert_eq!(part2(&input), Ok(5031));
+
+    let trace = trace(&input, 2, &mut Recorder::off()).unwrap();
+    let lines = trace.render(&input.0);
+    let lines = lines.lines().map(str::trim_end).collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        [
+            "        >>v#",
+            "        .#v.",
+            "        #.v.",
+            "        ..v.",
+            "...#..^...v#",
+            ".>>>>>^.#.>>",
+            ".^#....#....",
+            ".^........#.",
+            "        ...#..v.",
+            "        .....#v.",
+            "        .#v<<<<.",
+            "        ..v...#.",
+        ]
+    );
+    assert_eq!(
+        trace.warp_log(),
+        "instruction 5: right edge of row 6 column 12 -> \
+         top edge of row 9 column 15, facing v\n\
+         instruction 9: bottom edge of row 12 column 11 -> \
+         bottom edge of row 8 column 2, facing ^\n"
+    );
+
+    // The 11 nets of a cube, one character per face
+    let nets = [
+        "#...\n####\n#...",
+        "#...\n####\n.#..",
+        "#...\n####\n..#.",
+        "#...\n####\n...#",
+        ".#..\n####\n.#..",
+        ".#..\n####\n..#.",
+        "##..\n.###\n.#..",
+        "##..\n.###\n..#.",
+        "##..\n.###\n...#",
+        "##..\n.##.\n..##",
+        "###..\n..###",
+    ];
+
+    for net in nets {
+        for size in [1, 3] {
+            let mut map = String::new();
+            for row in net.lines() {
+                let line = row
+                    .chars()
+                    .map(|c| if c == '#' { "." } else { " " }.repeat(size))
+                    .collect::<String>();
+                map += &format!("{line}\n").repeat(size);
+            }
+            let (field, _) = parse(&(map + "\n1")).unwrap();
+            let cube = Cube::fold(&field).unwrap();
+
+            for (face, edges) in cube.edges.iter().enumerate() {
+                for (facing, &(to, back)) in edges.iter().enumerate() {
+                    assert_eq!(cube.edges[to][back], (face, facing));
+                }
+            }
+
+            // Walking straight ahead on an empty cube leads back to the
+            // start after going around it once.
+            let wraps = cube_wraps(&field).unwrap();
+            for start in field.positions() {
+                for start_facing in 0..4 {
+                    let (mut pos, mut facing) = (start, start_facing);
+                    let by = 4 * size as i64;
+                    step(&mut pos, &mut facing, by, &field, &wraps, None);
+                    assert_eq!((pos, facing), (start, start_facing));
+                }
+            }
+        }
+    }
+
+    assert!(Cube::fold(&parse(".....\n\n1").unwrap().0).is_err());
+    let input = parse("....\n....\n\n1").unwrap();
+    assert!(Cube::fold(&input.0).is_err());
+    assert_eq!(part1(&input), 1008);
+    assert_eq!(
+        part2(&input),
+        Err("no cube, the map doesn't have six square faces".to_owned())
+    );
+}
@@ -0,0 +1,182 @@
+use std::marker::PhantomData;
+
+use ahash::AHashMap;
+
+use crate::{
+    error::ParseError,
+    grid::{GridView, Pos, SparseGrid},
+    solution::{Solution, Solver},
+    visual::{Frame, Recorder},
+};
+
+/// The positions of the elves.
+type Elves = SparseGrid<()>;
+
+fn parse(input: &str) -> Result<Elves, ParseError> {
+    SparseGrid::parse(input, |_, c| match c {
+        '#' => Ok(Some(())),
+        '.' => Ok(None),
+        _ => Err("invalid tile"),
+    })
+}
+
+#[derive(Debug)]
+enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir {
+    fn adj(&self, (x, y): Pos) -> [Pos; 3] {
+        match self {
+            Dir::North => [(x - 1, y - 1), (x, y - 1), (x + 1, y - 1)],
+            Dir::East => [(x + 1, y - 1), (x + 1, y), (x + 1, y + 1)],
+            Dir::South => [(x - 1, y + 1), (x, y + 1), (x + 1, y + 1)],
+            Dir::West => [(x - 1, y - 1), (x - 1, y), (x - 1, y + 1)],
+        }
+    }
+}
+
+fn count_empty_space(map: &Elves) -> i64 {
+    map.bounds().map_or(0, |b| b.area()) - map.len() as i64
+}
+
+fn alone(map: &Elves, elf: Pos) -> bool {
+    map.neighbours8(elf).next().is_none()
+}
+
+/// Spreads the elves out until none of them moves or `max_rounds` rounds are
+/// over, calling `round_done` after every round with its number. Returns the
+/// number of the last round and the final positions.
+fn simulate(
+    map: &Elves,
+    max_rounds: i64,
+    mut round_done: impl FnMut(i64, &Elves),
+) -> (i64, Elves) {
+    let mut dir_order = [Dir::North, Dir::South, Dir::West, Dir::East]
+        .iter()
+        .cycle();
+    let mut map = map.clone();
+
+    for round in 1..=max_rounds {
+        let mut proposed_moves = AHashMap::<Pos, Vec<Pos>>::default();
+        let mut no_move = true;
+
+        for (elf, _) in map.iter() {
+            if alone(&map, elf) {
+                continue;
+            }
+
+            for dir in dir_order.clone().take(4) {
+                let adj = dir.adj(elf);
+                if adj.iter().all(|&p| !map.contains(p)) {
+                    if let Some(elf_list) = proposed_moves.get_mut(&adj[1]) {
+                        elf_list.push(elf);
+                    } else {
+                        proposed_moves.insert(adj[1], vec![elf]);
+                    }
+                    break;
+                }
+            }
+        }
+
+        for (dest, elf) in proposed_moves {
+            if elf.len() == 1 {
+                map.remove(elf[0]);
+                map.insert(dest, ());
+                no_move = false;
+            }
+        }
+
+        dir_order.next();
+        round_done(round, &map);
+
+        if no_move {
+            return (round, map);
+        }
+    }
+
+    (max_rounds, map)
+}
+
+fn part1(map: &Elves) -> i64 {
+    count_empty_space(&simulate(map, 10, |_, _| ()).1)
+}
+
+fn part2(map: &Elves) -> i64 {
+    simulate(map, i64::MAX, |_, _| ()).0
+}
+
+/// Records every round, all drawn with the bounds of the whole simulation so
+/// that the frames line up.
+fn visualise(map: &Elves, recorder: &mut Recorder) {
+    let mut bounds = map.bounds();
+    simulate(map, i64::MAX, |_, map| {
+        if let (Some(bounds), Some(round)) = (&mut bounds, map.bounds()) {
+            bounds.extend(round.min);
+            bounds.extend(round.max);
+        }
+    });
+    let Some(bounds) = bounds else {
+        return;
+    };
+
+    let frame = |map: &Elves| {
+        Frame::render(map, bounds, |_, elf| match elf {
+            Some(()) => '#',
+            None => '.',
+        })
+    };
+
+    recorder.frame(|| frame(map));
+    simulate(map, i64::MAX, |_, map| recorder.frame(|| frame(map)));
+}
+
+struct Day23;
+
+impl Solution for Day23 {
+    type Input = Elves;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        Ok(part2(input))
+    }
+
+    fn visualise(
+        input: &Self::Input,
+        _part: u8,
+        recorder: &mut Recorder,
+    ) -> Result<bool, String> {
+        visualise(input, recorder);
+        Ok(true)
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day23>;
+
+#[test]
+fn test_day23() {
+    let input = "\
+....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), 110);
+    assert_eq!(part2(&input), 20);
+}
@@ -0,0 +1,227 @@
+use std::marker::PhantomData;
+
+use ahash::AHashMap;
+
+use crate::{
+    error::ParseError,
+    search,
+    solution::{Solution, Solver},
+};
+
+#[derive(Debug)]
+struct Board {
+    blizzards: Vec<(i64, i64, char)>,
+    width: i64,
+    height: i64,
+}
+
+fn parse(input: &str) -> Result<Board, ParseError> {
+    let mut board = Board {
+        blizzards: vec![],
+        width: 0,
+        height: 0,
+    };
+
+    for (y, line) in input.lines().enumerate() {
+        board.width = 0;
+        for (x, c) in line.char_indices() {
+            match c {
+                '>' | 'v' | '^' | '<' => {
+                    board.blizzards.push((x as i64 - 1, y as i64 - 1, c));
+                }
+                '#' | '.' => (),
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        &line[x..x + c.len_utf8()],
+                        "invalid tile",
+                    ))
+                }
+            }
+
+            board.width += 1;
+        }
+
+        board.height += 1;
+    }
+
+    if board.width < 3 || board.height < 3 {
+        return Err(ParseError::after(input, input, "valley is too small"));
+    }
+
+    board.width -= 2;
+    board.height -= 2;
+
+    Ok(board)
+}
+
+fn collision(
+    pos: &(i64, i64),
+    board: &Board,
+    time: i64,
+    cache: &mut Cache,
+) -> bool {
+    let state = (pos.0, pos.1, time % board.height, time % board.width);
+    if let Some(col) = cache.collision_cache.get(&state) {
+        return *col;
+    }
+
+    let mut collision_detected = false;
+
+    for &(x, y, dir) in &board.blizzards {
+        match dir {
+            '>' => {
+                if *pos == ((x + time).rem_euclid(board.width), y) {
+                    collision_detected = true;
+                    break;
+                }
+            }
+            'v' => {
+                if *pos == (x, (y + time).rem_euclid(board.height)) {
+                    collision_detected = true;
+                    break;
+                }
+            }
+            '^' => {
+                if *pos == (x, (y - time).rem_euclid(board.height)) {
+                    collision_detected = true;
+                    break;
+                }
+            }
+            '<' => {
+                if *pos == ((x - time).rem_euclid(board.width), y) {
+                    collision_detected = true;
+                    break;
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    cache.collision_cache.insert(state, collision_detected);
+
+    collision_detected
+}
+
+struct Cache {
+    collision_cache: AHashMap<(i64, i64, i64, i64), bool>,
+}
+
+impl Cache {
+    fn new() -> Self {
+        Cache {
+            collision_cache: AHashMap::new(),
+        }
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The earliest time at which `goal` can be reached when leaving `start` at
+/// `time`, waiting in place being allowed. Returns `None` if the blizzards
+/// never let anyone through.
+fn walk(
+    start: (i64, i64),
+    board: &Board,
+    time: i64,
+    cache: &mut Cache,
+    goal: (i64, i64),
+) -> Option<i64> {
+    let free = |pos: &(i64, i64)| {
+        *pos == start
+            || *pos == goal
+            || ((0..board.width).contains(&pos.0)
+                && (0..board.height).contains(&pos.1))
+    };
+    // The blizzards repeat after this many minutes, so positions are only
+    // visited once per phase and the search space stays finite.
+    let period = board.width / gcd(board.width, board.height) * board.height;
+
+    let paths = search::astar(
+        (start, time % period),
+        |&((x, y), phase)| {
+            let next = (phase + 1) % period;
+            [(x, y), (x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)]
+                .into_iter()
+                .filter(|pos| free(pos) && !collision(pos, board, next, cache))
+                .map(move |pos| ((pos, next), 1))
+                .collect::<Vec<_>>()
+        },
+        |&((x, y), _)| (x - goal.0).abs() + (y - goal.1).abs(),
+        |&(pos, _)| pos == goal,
+    );
+
+    paths.goal().map(|(_, minutes)| time + minutes)
+}
+
+fn part1(board: &Board) -> Option<i64> {
+    walk(
+        (0, -1),
+        board,
+        0,
+        &mut Cache::new(),
+        (board.width - 1, board.height),
+    )
+}
+
+fn part2(board: &Board) -> Option<i64> {
+    let mut cache = Cache::new();
+    let start = (0, -1);
+    let goal = (board.width - 1, board.height);
+    let first_dist = walk(start, board, 0, &mut cache, goal)?;
+    let second_dist = walk(goal, board, first_dist, &mut cache, start)?;
+    walk(start, board, second_dist, &mut cache, goal)
+}
+
+const NO_WAY: &str = "the blizzards block every way through the valley";
+
+struct Day24;
+
+impl Solution for Day24 {
+    type Input = Board;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        part1(input).ok_or_else(|| NO_WAY.to_owned())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String> {
+        part2(input).ok_or_else(|| NO_WAY.to_owned())
+    }
+}
+
+pub const SOLVER: &dyn Solver = &PhantomData::<Day24>;
+
+#[test]
+fn test_day24() {
+    let input = "\
+#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), Some(18));
+    assert_eq!(part2(&input), Some(54));
+
+    let input = "\
+#.#
+#v#
+#.#";
+    let input = parse(input).unwrap();
+
+    assert_eq!(part1(&input), None);
+}
@@ -1,5 +1,29 @@
-fn parse(input: &str) -> Vec<&str> {
-    input.lines().collect()
+use std::marker::PhantomData;
+
+use crate::{
+    error::ParseError,
+    solution::{Solution, Solver},
+};
+
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut numbers = vec![];
+
+    for line in input.lines() {
+        if let Some((idx, c)) = line
+            .char_indices()
+            .find(|(_, c)| !matches!(c, '-' | '=' | '0' | '1' | '2'))
+        {
+            return Err(ParseError::at(
+                input,
+                &line[idx..idx + c.len_utf8()],
+                "invalid SNAFU digit",
+            ));
+        }
+
+        numbers.push(line.to_owned());
+    }
+
+    Ok(numbers)
 }
 
 fn snafu2num(snafu: &str) -> i64 {
@@ -46,16 +70,33 @@ fn num2snafu(mut num: i64) -> String {
     snafu_num.chars().rev().collect()
 }
 
-fn solve(puzzle: &[&str]) -> String {
+fn solve(puzzle: &[String]) -> String {
     num2snafu(puzzle.iter().map(|s| snafu2num(s)).sum())
 }
 
-fn main() {
-    let input = include_str!("../../input/input25.txt");
-    let input = parse(input);
-    println!("answer = {}", solve(&input));
+struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<String>;
+    type Part1 = String;
+    type Part2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String> {
+        Ok(solve(input))
+    }
+
+    fn part2(_: &Self::Input) -> Result<Self::Part2, String> {
+        // There is no second puzzle on the last day.
+        Ok("-")
+    }
 }
 
+pub const SOLVER: &dyn Solver = &PhantomData::<Day25>;
+
 #[test]
 fn test_day25() {
     let input = "\
@@ -72,7 +113,7 @@ fn test_day25() {
 12
 1=
 122";
-    let input = parse(input);
+    let input = parse(input).unwrap();
 
     assert_eq!(snafu2num("1=-0-2"), 1747);
     assert_eq!(snafu2num("12111"), 906);
@@ -0,0 +1,58 @@
+use crate::solution::Day;
+
+macro_rules! days {
+    ($($module:ident => $number:literal),* $(,)?) => {
+        $(mod $module;)*
+
+        /// All days that have a Rust solution, in puzzle order.
+        pub const DAYS: &[Day] = &[$(Day {
+            number: $number,
+            solver: $module::SOLVER,
+        }),*];
+
+        /// One test per day that checks the real input against
+        /// `answers.toml`, skipped if either of them is missing.
+        #[cfg(test)]
+        mod answers {
+            $(
+                #[test]
+                fn $module() {
+                    crate::verify::check_stored_answers($number);
+                }
+            )*
+        }
+    };
+}
+
+days! {
+    day01 => 1,
+    day02 => 2,
+    day03 => 3,
+    day04 => 4,
+    day05 => 5,
+    day06 => 6,
+    day07 => 7,
+    day08 => 8,
+    day09 => 9,
+    day10 => 10,
+    day11 => 11,
+    day12 => 12,
+    day13 => 13,
+    day14 => 14,
+    day15 => 15,
+    day16 => 16,
+    day17 => 17,
+    day18 => 18,
+    day19 => 19,
+    day20 => 20,
+    day21 => 21,
+    day22 => 22,
+    day23 => 23,
+    day24 => 24,
+    day25 => 25,
+}
+
+/// Looks up the solution for `day`.
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == day)
+}
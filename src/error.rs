@@ -0,0 +1,129 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// An error in a puzzle input, pointing at the offending text.
+///
+/// Line and column are 1-based. A line of 0 means the position is unknown,
+/// which only happens if the offending text isn't part of the parsed input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            day: 0,
+            line,
+            column,
+            text: text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for `text`, which has to be a slice of `input`. The
+    /// position is derived from where `text` lies inside of `input`.
+    pub fn at(input: &str, text: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize).wrapping_sub(start);
+
+        if offset > input.len() {
+            return ParseError::new(0, 0, text, message);
+        }
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        ParseError::new(line, column, text, message)
+    }
+
+    /// Creates an error for a missing token right after `text`, which has to
+    /// be a slice of `input`.
+    pub fn after(input: &str, text: &str, message: impl Into<String>) -> Self {
+        ParseError::at(input, &text[text.len()..], message)
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = day;
+        self
+    }
+
+    /// Renders the error together with the offending line of `input` and a
+    /// caret marking the offending text.
+    pub fn render(&self, input: &str) -> String {
+        let mut out = format!("error: {self}");
+
+        let Some(source) = input.lines().nth(self.line.wrapping_sub(1)) else {
+            return out;
+        };
+
+        let number = self.line.to_string();
+        let pad = " ".repeat(number.len());
+        let indent = " ".repeat(self.column - 1);
+        let carets = "^".repeat(usize::max(self.text.chars().count(), 1));
+
+        out += &format!("\n{pad} |\n{number} | {source}\n{pad} | {indent}");
+        out += &carets;
+        out
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.day != 0 {
+            write!(f, "day {}, ", self.day)?;
+        }
+
+        if self.line != 0 {
+            write!(f, "line {}, column {}: ", self.line, self.column)?;
+        }
+
+        write!(f, "{}", self.message)?;
+
+        if !self.text.is_empty() {
+            write!(f, " `{}`", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `text`, a slice of `input`, with [`FromStr`].
+pub fn parse_at<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(input, text, "invalid number"))
+}
+
+#[test]
+fn test_parse_error() {
+    let input = "R 4\nU x\nL 3";
+    let err = parse_at::<i64>(input, &input[6..7])
+        .unwrap_err()
+        .with_day(9);
+
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(
+        err.to_string(),
+        "day 9, line 2, column 3: invalid number `x`"
+    );
+    assert_eq!(
+        err.render(input),
+        "error: day 9, line 2, column 3: invalid number `x`\n  |\n2 | U x\n  |   ^"
+    );
+
+    let err = ParseError::after(input, &input[8..11], "expected a newline");
+    assert_eq!((err.line, err.column), (3, 4));
+    assert_eq!(ParseError::at(input, &String::from("R"), "x").line, 0);
+}
@@ -0,0 +1,470 @@
+use std::ops::{Index, IndexMut};
+
+use ahash::AHashMap;
+
+use crate::error::ParseError;
+
+/// A position on a grid as `(x, y)`, with y growing downwards like the lines
+/// of a puzzle input.
+pub type Pos = (i64, i64);
+
+/// Offsets of the orthogonal neighbours, clockwise starting with up.
+pub const NEIGHBOURS4: [Pos; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the orthogonal and diagonal neighbours, clockwise starting with
+/// up.
+pub const NEIGHBOURS8: [Pos; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// An inclusive bounding box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Pos,
+    pub max: Pos,
+}
+
+impl Bounds {
+    /// The smallest box containing all `positions`, or `None` if there are
+    /// none.
+    pub fn of(positions: impl IntoIterator<Item = Pos>) -> Option<Self> {
+        let mut positions = positions.into_iter();
+        let first = positions.next()?;
+        let mut bounds = Bounds {
+            min: first,
+            max: first,
+        };
+
+        for pos in positions {
+            bounds.extend(pos);
+        }
+
+        Some(bounds)
+    }
+
+    pub fn extend(&mut self, (x, y): Pos) {
+        self.min = (self.min.0.min(x), self.min.1.min(y));
+        self.max = (self.max.0.max(x), self.max.1.max(y));
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        (self.min.0..=self.max.0).contains(&x)
+            && (self.min.1..=self.max.1).contains(&y)
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.0 - self.min.0 + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.1 - self.min.1 + 1
+    }
+
+    pub fn area(&self) -> i64 {
+        self.width() * self.height()
+    }
+}
+
+/// The API shared by [`Grid`] and [`SparseGrid`].
+pub trait GridView {
+    type Cell;
+
+    /// The cell at `pos`, or `None` if it's outside of the grid or empty.
+    fn get(&self, pos: Pos) -> Option<&Self::Cell>;
+
+    /// The bounding box of all cells, or `None` if the grid is empty.
+    fn bounds(&self) -> Option<Bounds>;
+
+    /// The existing orthogonal neighbours of `pos`.
+    fn neighbours4(
+        &self,
+        pos: Pos,
+    ) -> impl Iterator<Item = (Pos, &Self::Cell)> + '_ {
+        offsets(pos, &NEIGHBOURS4).filter_map(|pos| Some((pos, self.get(pos)?)))
+    }
+
+    /// The existing orthogonal and diagonal neighbours of `pos`.
+    fn neighbours8(
+        &self,
+        pos: Pos,
+    ) -> impl Iterator<Item = (Pos, &Self::Cell)> + '_ {
+        offsets(pos, &NEIGHBOURS8).filter_map(|pos| Some((pos, self.get(pos)?)))
+    }
+
+    /// The cells of row `y` from left to right, skipping empty ones.
+    fn row(&self, y: i64) -> impl Iterator<Item = (Pos, &Self::Cell)> + '_ {
+        let (min, max) = self.bounds().map_or((0, -1), |b| (b.min.0, b.max.0));
+
+        (min..=max).filter_map(move |x| Some(((x, y), self.get((x, y))?)))
+    }
+
+    /// The cells of column `x` from top to bottom, skipping empty ones.
+    fn column(&self, x: i64) -> impl Iterator<Item = (Pos, &Self::Cell)> + '_ {
+        let (min, max) = self.bounds().map_or((0, -1), |b| (b.min.1, b.max.1));
+
+        (min..=max).filter_map(move |y| Some(((x, y), self.get((x, y))?)))
+    }
+
+    /// Renders the bounding box of the grid as text, one line per row.
+    /// `cell` also gets called for empty positions, so it can draw overlays.
+    fn render(
+        &self,
+        mut cell: impl FnMut(Pos, Option<&Self::Cell>) -> char,
+    ) -> String {
+        let Some(bounds) = self.bounds() else {
+            return String::new();
+        };
+        let mut out = String::new();
+
+        for y in bounds.min.1..=bounds.max.1 {
+            for x in bounds.min.0..=bounds.max.0 {
+                out.push(cell((x, y), self.get((x, y))));
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+fn offsets((x, y): Pos, offsets: &[Pos]) -> impl Iterator<Item = Pos> + '_ {
+    offsets.iter().map(move |(dx, dy)| (x + dx, y + dy))
+}
+
+/// Calls `cell` for every character of the lines of `text`, which has to be
+/// a slice of `input`. An error message returned by `cell` is reported at
+/// the offending character.
+fn parse_cells<T>(
+    input: &str,
+    text: &str,
+    mut cell: impl FnMut(Pos, char) -> Result<T, &'static str>,
+    mut row: impl FnMut(&str, Vec<T>) -> Result<(), ParseError>,
+) -> Result<(), ParseError> {
+    for (y, line) in text.lines().enumerate() {
+        let mut cells = vec![];
+
+        for (x, (offset, c)) in line.char_indices().enumerate() {
+            let pos = (x as i64, y as i64);
+            cells.push(cell(pos, c).map_err(|message| {
+                ParseError::at(
+                    input,
+                    &line[offset..offset + c.len_utf8()],
+                    message,
+                )
+            })?);
+        }
+
+        row(line, cells)?;
+    }
+
+    Ok(())
+}
+
+/// A rectangular grid storing every cell, with the top left at `(0, 0)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses all of `input`, see [`Grid::parse_in`].
+    pub fn parse(
+        input: &str,
+        cell: impl FnMut(Pos, char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        Grid::parse_in(input, input, cell)
+    }
+
+    /// Parses the lines of `text`, which has to be a slice of `input`, with
+    /// one cell per character. All lines need to have the same length.
+    pub fn parse_in(
+        input: &str,
+        text: &str,
+        cell: impl FnMut(Pos, char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let mut grid = Grid {
+            width: 0,
+            height: 0,
+            cells: vec![],
+        };
+
+        parse_cells(input, text, cell, |line, row| {
+            if grid.height != 0 && grid.width != row.len() {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "rows differ in length",
+                ));
+            }
+
+            grid.width = row.len();
+            grid.height += 1;
+            grid.cells.extend(row);
+            Ok(())
+        })?;
+
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, (x, y): Pos) -> Option<usize> {
+        let (x, y) = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.index(pos).is_some()
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width as i64, self.height as i64);
+
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> GridView for Grid<T> {
+    type Cell = T;
+
+    fn get(&self, pos: Pos) -> Option<&T> {
+        self.index(pos).map(|idx| &self.cells[idx])
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        (self.width != 0 && self.height != 0).then(|| Bounds {
+            min: (0, 0),
+            max: (self.width as i64 - 1, self.height as i64 - 1),
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} outside of grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} outside of grid"))
+    }
+}
+
+/// A grid that only stores occupied cells and is unbounded in every
+/// direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: AHashMap<Pos, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: AHashMap::default(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid::default()
+    }
+
+    /// Parses all of `input`, see [`SparseGrid::parse_in`].
+    pub fn parse(
+        input: &str,
+        cell: impl FnMut(Pos, char) -> Result<Option<T>, &'static str>,
+    ) -> Result<Self, ParseError> {
+        SparseGrid::parse_in(input, input, cell)
+    }
+
+    /// Parses the lines of `text`, which has to be a slice of `input`, with
+    /// one cell per character. Characters for which `cell` returns `None`
+    /// are left empty.
+    pub fn parse_in(
+        input: &str,
+        text: &str,
+        cell: impl FnMut(Pos, char) -> Result<Option<T>, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let mut grid = SparseGrid::new();
+        let mut y = 0;
+
+        parse_cells(input, text, cell, |_, row| {
+            for (x, cell) in row.into_iter().enumerate() {
+                if let Some(cell) = cell {
+                    grid.insert((x as i64, y), cell);
+                }
+            }
+
+            y += 1;
+            Ok(())
+        })?;
+
+        Ok(grid)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn insert(&mut self, pos: Pos, cell: T) -> Option<T> {
+        self.cells.insert(pos, cell)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// All occupied cells with their positions, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(&pos, cell)| (pos, cell))
+    }
+}
+
+impl<T> GridView for SparseGrid<T> {
+    type Cell = T;
+
+    fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        Bounds::of(self.cells.keys().copied())
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[test]
+fn test_grid() {
+    let input = "\
+#.#
+..#
+x#.";
+    let tile = |_, c| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err("invalid tile"),
+    };
+
+    let err = Grid::parse(input, tile).unwrap_err();
+    assert_eq!((err.line, err.column), (3, 1));
+    let err = Grid::parse("##\n#", tile).unwrap_err();
+    assert_eq!(
+        (err.line, err.message.as_str()),
+        (2, "rows differ in length")
+    );
+
+    let input = &input.replace('x', ".");
+    let grid = Grid::parse(input, tile).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 3));
+    assert_eq!(grid.get((2, 1)), Some(&true));
+    assert_eq!(grid.get((-1, 0)), None);
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.neighbours4((0, 0)).count(), 2);
+    assert_eq!(grid.neighbours8((1, 1)).filter(|(_, &c)| c).count(), 4);
+    assert_eq!(grid.row(2).map(|(p, _)| p).collect::<Vec<_>>().len(), 3);
+    assert!(grid.column(2).all(|((x, _), _)| x == 2));
+    assert_eq!(
+        grid.render(|_, c| if c == Some(&true) { '#' } else { '.' }),
+        format!("{input}\n")
+    );
+
+    let sparse = SparseGrid::parse(input, |_, c| {
+        tile((0, 0), c).map(|wall| wall.then_some('#'))
+    })
+    .unwrap();
+    assert_eq!(sparse.len(), 4);
+    assert_eq!(sparse.get((1, 2)), Some(&'#'));
+    assert_eq!(sparse.get((1, 1)), None);
+    assert_eq!(sparse.row(0).count(), 2);
+    assert_eq!(sparse.column(2).count(), 2);
+    assert_eq!(sparse.neighbours8((1, 1)).count(), 4);
+    assert_eq!(
+        sparse.bounds(),
+        Some(Bounds {
+            min: (0, 0),
+            max: (2, 2)
+        })
+    );
+    assert_eq!(
+        sparse.render(|_, c| *c.unwrap_or(&'.')),
+        format!("{input}\n")
+    );
+
+    let moved: SparseGrid<_> = sparse
+        .iter()
+        .map(|((x, y), &c)| ((x - 5, y * 2), c))
+        .collect();
+    let bounds = moved.bounds().unwrap();
+    assert_eq!((bounds.min, bounds.area()), ((-5, 0), 15));
+    assert!(bounds.contains((-3, 4)) && !bounds.contains((-3, 5)));
+}
@@ -1,5 +1,101 @@
-use std::path::PathBuf;
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::PathBuf,
+};
 
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod grid;
+pub mod scan;
+pub mod search;
+pub mod solution;
+pub mod verify;
+pub mod visual;
+
+/// Returns the path of a puzzle input file. The directory defaults to
+/// `input/` in the crate root and can be overridden with `AOC_INPUT_DIR`.
 pub fn input_file(filename: &str) -> PathBuf {
-    PathBuf::from(env!("CARGO_MANIFEST_DIR").to_owned() + "/input/" + filename)
+    let dir = env::var_os("AOC_INPUT_DIR").map_or_else(
+        || PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("input"),
+        PathBuf::from,
+    );
+
+    dir.join(filename)
+}
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `inputNN.txt` in the input directory, see [`input_file`].
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets a command line argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(arg))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: String,
+    error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot read input from {}: {}", self.source, self.error)?;
+
+        if self.is_not_found() {
+            write!(
+                f,
+                " (pass a file with --input or set AOC_INPUT_DIR to the \
+                 directory containing your inputs)"
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl InputError {
+    /// Whether the input file doesn't exist.
+    pub fn is_not_found(&self) -> bool {
+        self.error.kind() == io::ErrorKind::NotFound
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Reads the puzzle input for `day` from `source`.
+pub fn read_input(day: u8, source: &InputSource) -> Result<String, InputError> {
+    let (path, result) = match source {
+        InputSource::Default => {
+            let path = input_file(&format!("input{day:02}.txt"));
+            let result = fs::read_to_string(&path);
+            (path.display().to_string(), result)
+        }
+        InputSource::Path(path) => {
+            (path.display().to_string(), fs::read_to_string(path))
+        }
+        InputSource::Stdin => {
+            let mut input = String::new();
+            let result = io::stdin().read_to_string(&mut input).map(|_| input);
+            ("stdin".to_owned(), result)
+        }
+    };
+
+    result.map_err(|error| InputError {
+        source: path,
+        error,
+    })
 }
@@ -0,0 +1,277 @@
+use std::{iter, str::FromStr};
+
+use crate::error::{parse_at, ParseError};
+
+/// A part of a line, see [`pieces`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Piece<'a> {
+    Number(&'a str),
+    Text(&'a str),
+}
+
+/// Whether a number starts at byte `idx` of `line`. A `-` only counts as a
+/// sign if it isn't preceded by a letter or digit, so `2-4` is two numbers.
+fn number_starts(line: &[u8], idx: usize) -> bool {
+    match line[idx] {
+        b'0'..=b'9' => true,
+        b'-' => {
+            line.get(idx + 1).is_some_and(u8::is_ascii_digit)
+                && (idx == 0 || !line[idx - 1].is_ascii_alphanumeric())
+        }
+        _ => false,
+    }
+}
+
+/// Splits `line` into signed integers and the text between them.
+pub fn pieces(line: &str) -> impl Iterator<Item = Piece<'_>> {
+    let bytes = line.as_bytes();
+    let mut pos = 0;
+
+    iter::from_fn(move || {
+        if pos == bytes.len() {
+            return None;
+        }
+
+        let start = pos;
+        pos += 1;
+
+        if number_starts(bytes, start) {
+            while bytes.get(pos).is_some_and(u8::is_ascii_digit) {
+                pos += 1;
+            }
+
+            Some(Piece::Number(&line[start..pos]))
+        } else {
+            while pos < bytes.len() && !number_starts(bytes, pos) {
+                pos += 1;
+            }
+
+            Some(Piece::Text(&line[start..pos]))
+        }
+    })
+}
+
+/// The signed integers in `line`, as slices of it.
+pub fn tokens(line: &str) -> impl Iterator<Item = &str> {
+    pieces(line).filter_map(|piece| match piece {
+        Piece::Number(number) => Some(number),
+        Piece::Text(_) => None,
+    })
+}
+
+/// Parses all signed integers in `line`, which has to be a slice of `input`.
+pub fn integers<'a, T: FromStr>(
+    input: &'a str,
+    line: &'a str,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a {
+    tokens(line).map(move |token| parse_at(input, token))
+}
+
+/// Types made up of a fixed number of integers: arrays and tuples.
+pub trait Numbers: Sized {
+    const LEN: usize;
+
+    /// Parses exactly `LEN` tokens, which have to be slices of `input`.
+    fn from_tokens(input: &str, tokens: &[&str]) -> Result<Self, ParseError>;
+}
+
+impl<T: FromStr, const N: usize> Numbers for [T; N] {
+    const LEN: usize = N;
+
+    fn from_tokens(input: &str, tokens: &[&str]) -> Result<Self, ParseError> {
+        let values = tokens
+            .iter()
+            .map(|token| parse_at(input, token))
+            .collect::<Result<Vec<T>, _>>()?;
+
+        match values.try_into() {
+            Ok(values) => Ok(values),
+            Err(_) => panic!("expected {N} tokens"),
+        }
+    }
+}
+
+macro_rules! tuple_numbers {
+    ($len:literal: $($t:ident $idx:tt),*) => {
+        impl<$($t: FromStr),*> Numbers for ($($t,)*) {
+            const LEN: usize = $len;
+
+            fn from_tokens(
+                input: &str,
+                tokens: &[&str],
+            ) -> Result<Self, ParseError> {
+                Ok(($(parse_at::<$t>(input, tokens[$idx])?,)*))
+            }
+        }
+    };
+}
+
+tuple_numbers!(1: A 0);
+tuple_numbers!(2: A 0, B 1);
+tuple_numbers!(3: A 0, B 1, C 2);
+tuple_numbers!(4: A 0, B 1, C 2, D 3);
+tuple_numbers!(5: A 0, B 1, C 2, D 3, E 4);
+tuple_numbers!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+tuple_numbers!(7: A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple_numbers!(8: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Parses the signed integers in `line`, which has to be a slice of `input`,
+/// into an array or tuple. The text around them is ignored, but there have to
+/// be exactly as many numbers as `T` holds.
+pub fn numbers<T: Numbers>(input: &str, line: &str) -> Result<T, ParseError> {
+    let tokens: Vec<_> = tokens(line).collect();
+
+    if tokens.len() < T::LEN {
+        let message = match T::LEN {
+            1 => "expected a number".to_owned(),
+            n => format!("expected {n} numbers"),
+        };
+        return Err(ParseError::after(input, line, message));
+    }
+
+    if let Some(extra) = tokens.get(T::LEN) {
+        return Err(ParseError::at(input, extra, "unexpected number"));
+    }
+
+    T::from_tokens(input, &tokens)
+}
+
+/// The first character of `text`, or an empty slice at its end.
+fn head(text: &str) -> &str {
+    &text[..text.chars().next().map_or(0, char::len_utf8)]
+}
+
+/// Matches `literal` at the start of `text` and returns the rest. Whitespace
+/// in `literal` matches any non-empty run of whitespace.
+fn expect<'a>(
+    input: &str,
+    text: &'a str,
+    literal: &str,
+) -> Result<&'a str, ParseError> {
+    let mut rest = text;
+    let mut chars = literal.chars().peekable();
+    let mismatch = |rest| {
+        ParseError::at(input, head(rest), format!("expected `{literal}`"))
+    };
+
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+            let trimmed = rest.trim_start();
+            if trimmed.len() == rest.len() {
+                return Err(mismatch(rest));
+            }
+            rest = trimmed;
+        } else if let Some(after) = rest.strip_prefix(c) {
+            rest = after;
+        } else {
+            return Err(mismatch(rest));
+        }
+    }
+
+    Ok(rest)
+}
+
+/// Parses `line`, which has to be a slice of `input`, according to
+/// `template`, in which every `{}` stands for a signed integer and all other
+/// text has to match exactly, e.g. `"move {} from {} to {}"`.
+///
+/// # Panics
+///
+/// If `template` doesn't have as many placeholders as `T` holds numbers.
+pub fn template<T: Numbers>(
+    input: &str,
+    line: &str,
+    template: &str,
+) -> Result<T, ParseError> {
+    assert_eq!(
+        template.matches("{}").count(),
+        T::LEN,
+        "wrong number of placeholders in `{template}`"
+    );
+
+    let mut rest = line;
+    let mut tokens = vec![];
+
+    for (idx, literal) in template.split("{}").enumerate() {
+        if idx > 0 {
+            let Some(Piece::Number(token)) = pieces(rest).next() else {
+                return Err(ParseError::at(
+                    input,
+                    head(rest),
+                    "expected a number",
+                ));
+            };
+
+            tokens.push(token);
+            rest = &rest[token.len()..];
+        }
+
+        rest = expect(input, rest, literal)?;
+    }
+
+    if !rest.is_empty() {
+        return Err(ParseError::at(input, rest, "unexpected text"));
+    }
+
+    T::from_tokens(input, &tokens)
+}
+
+#[test]
+fn test_scan() {
+    let line = "x=-3, y=12: 2-4 and -7";
+    assert_eq!(
+        tokens(line).collect::<Vec<_>>(),
+        ["-3", "12", "2", "4", "-7"]
+    );
+    assert_eq!(
+        pieces("10R5L-2").collect::<Vec<_>>(),
+        [
+            Piece::Number("10"),
+            Piece::Text("R"),
+            Piece::Number("5"),
+            Piece::Text("L-"),
+            Piece::Number("2")
+        ]
+    );
+
+    let [a, b, c, d, e] = numbers::<[i64; 5]>(line, line).unwrap();
+    assert_eq!((a, b, c, d, e), (-3, 12, 2, 4, -7));
+    let (x, y): (i32, u8) = numbers(line, &line[..10]).unwrap();
+    assert_eq!((x, y), (-3, 12));
+
+    let err = numbers::<[i64; 6]>(line, line).unwrap_err();
+    assert_eq!(
+        (err.column, err.message.as_str()),
+        (23, "expected 6 numbers")
+    );
+    let err = numbers::<[i64; 4]>(line, line).unwrap_err();
+    assert_eq!((err.column, err.text.as_str()), (21, "-7"));
+    let err = numbers::<(u8, u8)>(line, &line[..10]).unwrap_err();
+    assert_eq!(err.message, "invalid number");
+
+    let input = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+    let sensor = "Sensor at x={}, y={}: closest beacon is at x={}, y={}";
+    let values: [i64; 4] = template(input, input, sensor).unwrap();
+    assert_eq!(values, [2, -18, -2, 15]);
+
+    let wrapped = "Sensor at  x=2,\n  y=-18: closest beacon is at x=-2, y=15";
+    assert!(template::<[i64; 4]>(wrapped, wrapped, sensor).is_ok());
+
+    let input = "Sensor at x=2, z=-18: closest beacon is at x=-2, y=15";
+    let err = template::<[i64; 4]>(input, input, sensor).unwrap_err();
+    assert_eq!((err.column, err.text.as_str()), (16, "z"));
+    assert_eq!(err.message, "expected `, y=`");
+
+    let input = "Sensor at x=a, y=-18: closest beacon is at x=-2, y=15";
+    let err = template::<[i64; 4]>(input, input, sensor).unwrap_err();
+    assert_eq!(
+        (err.column, err.message.as_str()),
+        (13, "expected a number")
+    );
+
+    let input = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15.";
+    let err = template::<[i64; 4]>(input, input, sensor).unwrap_err();
+    assert_eq!((err.column, err.message.as_str()), (54, "unexpected text"));
+}
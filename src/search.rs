@@ -0,0 +1,226 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use ahash::AHashMap;
+
+/// The outcome of a search: the cost of every reached node and the node it
+/// was reached from.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    costs: AHashMap<N, C>,
+    predecessors: AHashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new() -> Self {
+        Paths {
+            costs: AHashMap::default(),
+            predecessors: AHashMap::default(),
+            goal: None,
+        }
+    }
+
+    /// The cost of the cheapest path to `node`, if it was reached.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// The goal the search stopped at, with its cost.
+    pub fn goal(&self) -> Option<(&N, C)> {
+        let goal = self.goal.as_ref()?;
+        Some((goal, self.costs[goal]))
+    }
+
+    /// The cheapest path from one of the start nodes to `node`, including
+    /// both ends.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.costs.get(node)?;
+
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.predecessors.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// All reached nodes with their costs, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.costs.iter().map(|(node, &cost)| (node, cost))
+    }
+
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+}
+
+/// Breadth-first search from `start`, where every edge costs 1. Stops at the
+/// first node for which `is_goal` returns true, pass `|_| false` to reach
+/// everything.
+pub fn bfs<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    multi_bfs([start], neighbours, is_goal)
+}
+
+/// Breadth-first search from all `starts` at once, so every node ends up
+/// with the distance to its closest start.
+pub fn multi_bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = paths.costs.entry(start.clone()) {
+            entry.insert(0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+
+        let cost = paths.costs[&node] + 1;
+
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = paths.costs.entry(next.clone()) {
+                entry.insert(cost);
+                paths.predecessors.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm from `start` over `neighbours`, which yields the
+/// adjacent nodes together with the cost of the edge.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search from `start`. `heuristic` must never overestimate the cost to
+/// the closest goal, otherwise the path found might not be the cheapest.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    // The heap only holds indices into `queued`, so nodes don't need `Ord`.
+    let mut queued = vec![];
+    let mut heap = BinaryHeap::new();
+
+    paths.costs.insert(start.clone(), C::default());
+    heap.push(Reverse((heuristic(&start), 0)));
+    queued.push((start, C::default()));
+
+    while let Some(Reverse((_, idx))) = heap.pop() {
+        let (node, cost) = queued[idx].clone();
+
+        if cost > paths.costs[&node] {
+            continue;
+        }
+
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+
+            if paths.cost(&next).is_some_and(|old| old <= next_cost) {
+                continue;
+            }
+
+            paths.costs.insert(next.clone(), next_cost);
+            paths.predecessors.insert(next.clone(), node.clone());
+            heap.push(Reverse((next_cost + heuristic(&next), queued.len())));
+            queued.push((next, next_cost));
+        }
+    }
+
+    paths
+}
+
+#[test]
+fn test_search() {
+    // 0 - 1 - 2 - 3, plus a long edge from 0 to 3 and a detour 1 - 4 - 3
+    let edges = |n: &u32| -> Vec<(u32, u32)> {
+        match n {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(0, 1), (2, 1), (4, 1)],
+            2 => vec![(1, 1), (3, 5)],
+            4 => vec![(1, 1), (3, 1)],
+            _ => vec![],
+        }
+    };
+    let nodes = |n: &u32| edges(n).into_iter().map(|(n, _)| n);
+
+    let paths = bfs(0, nodes, |_| false);
+    assert_eq!(paths.len(), 5);
+    assert_eq!(paths.cost(&3), Some(1));
+    assert_eq!(paths.cost(&2), Some(2));
+    assert_eq!(paths.path(&2), Some(vec![0, 1, 2]));
+    assert_eq!(paths.goal(), None);
+
+    let paths = bfs(0, nodes, |&n| n == 1);
+    assert_eq!(paths.goal(), Some((&1, 1)));
+    assert_eq!(paths.path(&5), None);
+
+    let paths = multi_bfs([2, 4], nodes, |_| false);
+    assert_eq!(paths.cost(&0), Some(2));
+    assert_eq!(paths.path(&3), Some(vec![2, 3]));
+
+    let paths = dijkstra(0, edges, |&n| n == 3);
+    assert_eq!(paths.goal(), Some((&3, 3)));
+    assert_eq!(paths.path(&3), Some(vec![0, 1, 4, 3]));
+
+    let paths = astar(0, edges, |&n| u32::from(n != 3), |_| false);
+    assert_eq!(paths.cost(&3), Some(3));
+    assert_eq!(paths.cost(&2), Some(2));
+    assert!(paths.iter().all(|(n, c)| c <= 3 && *n != 5));
+}
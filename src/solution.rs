@@ -0,0 +1,159 @@
+use std::{any::Any, fmt::Display, marker::PhantomData};
+
+use crate::{error::ParseError, visual::Recorder};
+
+/// A puzzle solution split into its three phases.
+///
+/// `parse` turns the raw puzzle input into `Input`, which both parts then
+/// only borrow. Parts that need to mutate their input clone it first.
+///
+/// A part returns an error message if the input parses but has no answer,
+/// e.g. because a goal is unreachable.
+pub trait Solution {
+    type Input: 'static;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String>;
+
+    /// Runs `part` while recording its progress into `recorder`. Returns
+    /// false for days without a visualisation, and an error message like the
+    /// parts do.
+    fn visualise(
+        _input: &Self::Input,
+        _part: u8,
+        _: &mut Recorder,
+    ) -> Result<bool, String> {
+        Ok(false)
+    }
+
+    /// Extra output about the puzzle besides the answers, selected by `name`,
+    /// e.g. a graph. Returns `None` for unknown names and an error message
+    /// for invalid options in `name`.
+    fn report(
+        _input: &Self::Input,
+        _name: &str,
+    ) -> Option<Result<String, String>> {
+        None
+    }
+}
+
+/// Type-erased view of a [`Solution`], so that every day fits into one
+/// registry. The parsed input is passed around as `dyn Any` and the answers
+/// are already formatted.
+pub trait Solver: Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn part1(&self, input: &dyn Any) -> Result<String, String>;
+    fn part2(&self, input: &dyn Any) -> Result<String, String>;
+    fn visualise(
+        &self,
+        input: &dyn Any,
+        part: u8,
+        _: &mut Recorder,
+    ) -> Result<bool, String>;
+    fn report(
+        &self,
+        input: &dyn Any,
+        name: &str,
+    ) -> Option<Result<String, String>>;
+}
+
+impl<S: Solution + Sync> Solver for PhantomData<S> {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Result<String, String> {
+        S::part1(downcast::<S>(input)).map(|answer| answer.to_string())
+    }
+
+    fn part2(&self, input: &dyn Any) -> Result<String, String> {
+        S::part2(downcast::<S>(input)).map(|answer| answer.to_string())
+    }
+
+    fn visualise(
+        &self,
+        input: &dyn Any,
+        part: u8,
+        recorder: &mut Recorder,
+    ) -> Result<bool, String> {
+        S::visualise(downcast::<S>(input), part, recorder)
+    }
+
+    fn report(
+        &self,
+        input: &dyn Any,
+        name: &str,
+    ) -> Option<Result<String, String>> {
+        S::report(downcast::<S>(input), name)
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input {
+    input
+        .downcast_ref()
+        .expect("input was parsed by a different solution")
+}
+
+/// Which part(s) of a puzzle to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
+}
+
+impl Part {
+    pub fn includes(self, part: u8) -> bool {
+        matches!(
+            (self, part),
+            (Part::Both, _) | (Part::One, 1) | (Part::Two, 2)
+        )
+    }
+}
+
+/// A registry entry: the puzzle day and its solver.
+pub struct Day {
+    pub number: u8,
+    pub solver: &'static dyn Solver,
+}
+
+impl Day {
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        self.solver
+            .parse(input)
+            .map_err(|e| e.with_day(self.number))
+    }
+
+    /// Runs `part` (1 or 2) on input returned by [`Day::parse`] and formats
+    /// the answer.
+    pub fn answer(&self, parsed: &dyn Any, part: u8) -> Result<String, String> {
+        match part {
+            1 => self.solver.part1(parsed),
+            _ => self.solver.part2(parsed),
+        }
+    }
+
+    /// Runs `part` on input returned by [`Day::parse`] while recording it.
+    /// Returns false if this day has no visualisation.
+    pub fn visualise(
+        &self,
+        parsed: &dyn Any,
+        part: u8,
+        recorder: &mut Recorder,
+    ) -> Result<bool, String> {
+        self.solver.visualise(parsed, part, recorder)
+    }
+
+    /// Creates the report called `name` from input returned by
+    /// [`Day::parse`], if this day has one.
+    pub fn report(
+        &self,
+        parsed: &dyn Any,
+        name: &str,
+    ) -> Option<Result<String, String>> {
+        self.solver.report(parsed, name)
+    }
+}
@@ -0,0 +1,164 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path, path::PathBuf};
+
+use serde::Deserialize;
+
+use crate::{error::ParseError, solution::Day};
+
+/// Returns the path of `answers.toml`, which lives next to the `input/`
+/// directory in the crate root.
+pub fn answers_file() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Integer(i64),
+    Text(String),
+}
+
+impl Answer {
+    fn to_text(&self) -> String {
+        match self {
+            Answer::Integer(n) => n.to_string(),
+            Answer::Text(s) => s.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+/// The known answers per day, read from a TOML file of the form
+///
+/// ```toml
+/// [day01]
+/// part1 = 74711
+/// part2 = 209481
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    days: BTreeMap<u8, [Option<String>; 2]>,
+}
+
+impl Answers {
+    pub fn parse(toml: &str) -> Result<Self, String> {
+        let table: BTreeMap<String, DayAnswers> =
+            toml::from_str(toml).map_err(|e| e.to_string())?;
+        let mut days = BTreeMap::new();
+
+        for (key, answers) in table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("invalid day `{key}`"))?;
+            let text = |a: &Option<Answer>| a.as_ref().map(Answer::to_text);
+
+            days.insert(day, [text(&answers.part1), text(&answers.part2)]);
+        }
+
+        Ok(Answers { days })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let toml = fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+
+        Answers::parse(&toml).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.days.get(&day)?[part as usize - 1].as_deref()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// There is no stored answer, so the part wasn't run.
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => f.pad("pass"),
+            Status::Fail { .. } => f.pad("FAIL"),
+            Status::Missing => f.pad("missing"),
+        }
+    }
+}
+
+/// Runs both parts of `day` that have a stored answer and compares the
+/// results. Surrounding whitespace is ignored, so multi-line answers can be
+/// written naturally in TOML.
+pub fn check(
+    day: &Day,
+    input: &str,
+    answers: &Answers,
+) -> Result<[Status; 2], ParseError> {
+    let parsed = day.parse(input)?;
+    let mut status = [Status::Missing, Status::Missing];
+
+    for part in 1..=2 {
+        let Some(expected) = answers.get(day.number, part) else {
+            continue;
+        };
+
+        let actual = day
+            .answer(parsed.as_ref(), part)
+            .unwrap_or_else(|e| format!("error: {e}"));
+
+        status[part as usize - 1] = if actual.trim() == expected.trim() {
+            Status::Pass
+        } else {
+            Status::Fail {
+                expected: expected.to_owned(),
+                actual,
+            }
+        };
+    }
+
+    Ok(status)
+}
+
+#[test]
+fn test_answers() {
+    let answers =
+        Answers::parse("[day01]\npart1 = 24000\n\n[day05]\npart1 = \"CMZ\"\n")
+            .unwrap();
+
+    assert_eq!(answers.get(1, 1), Some("24000"));
+    assert_eq!(answers.get(1, 2), None);
+    assert_eq!(answers.get(5, 1), Some("CMZ"));
+    assert_eq!(answers.get(2, 1), None);
+    assert!(Answers::parse("[one]\npart1 = 1\n").is_err());
+}
+
+/// Test helper: checks `day` against the stored answers, if its input and
+/// `answers.toml` exist.
+#[cfg(test)]
+pub(crate) fn check_stored_answers(day: u8) {
+    let path = crate::input_file(&format!("input{day:02}.txt"));
+    let (Ok(input), Ok(answers)) =
+        (fs::read_to_string(path), Answers::load(&answers_file()))
+    else {
+        return;
+    };
+
+    let day = crate::days::find(day).unwrap();
+    let status = check(day, &input, &answers).unwrap();
+
+    for (part, status) in status.iter().enumerate() {
+        if let Status::Fail { expected, actual } = status {
+            panic!("part{}: expected {expected}, got {actual}", part + 1);
+        }
+    }
+}
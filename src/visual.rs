@@ -0,0 +1,467 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use ahash::AHashMap;
+
+use crate::grid::{Bounds, Grid, GridView, Pos};
+
+/// The time between two frames in the animated formats.
+const FRAME_DELAY_MS: u16 = 50;
+
+pub type Rgb = [u8; 3];
+
+/// One image of a visualisation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Frame {
+    Text(Grid<char>),
+    Colours(Grid<Rgb>),
+}
+
+impl Frame {
+    /// A text frame from lines of text. Shorter lines are padded with spaces.
+    pub fn text(text: &str) -> Self {
+        let width = text.lines().map(|l| l.chars().count()).max();
+        let height = text.lines().count();
+        let mut grid = Grid::new(width.unwrap_or(0), height, ' ');
+
+        for (y, line) in text.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                grid[(x as i64, y as i64)] = c;
+            }
+        }
+
+        Frame::Text(grid)
+    }
+
+    /// A text frame showing the part of `grid` inside `bounds`, drawn by
+    /// `cell` like in [`GridView::render`].
+    pub fn render<G: GridView>(
+        grid: &G,
+        bounds: Bounds,
+        mut cell: impl FnMut(Pos, Option<&G::Cell>) -> char,
+    ) -> Self {
+        let width = bounds.width().max(0) as usize;
+        let height = bounds.height().max(0) as usize;
+        let mut frame = Grid::new(width, height, ' ');
+
+        for (x, y) in frame.positions().collect::<Vec<_>>() {
+            let pos = (bounds.min.0 + x, bounds.min.1 + y);
+            frame[(x, y)] = cell(pos, grid.get(pos));
+        }
+
+        Frame::Text(frame)
+    }
+
+    pub fn width(&self) -> usize {
+        match self {
+            Frame::Text(grid) => grid.width(),
+            Frame::Colours(grid) => grid.width(),
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        match self {
+            Frame::Text(grid) => grid.height(),
+            Frame::Colours(grid) => grid.height(),
+        }
+    }
+
+    /// The frame as colours, with characters mapped by [`char_colour`].
+    pub fn to_colours(&self) -> Grid<Rgb> {
+        match self {
+            Frame::Text(grid) => grid.map(|&c| char_colour(c)),
+            Frame::Colours(grid) => grid.clone(),
+        }
+    }
+
+    /// The frame as lines for a terminal. Colour frames are drawn as blocks
+    /// using true colour escape codes.
+    pub fn to_terminal(&self) -> Vec<String> {
+        let mut lines = vec![String::new(); self.height()];
+
+        match self {
+            Frame::Text(grid) => {
+                for ((_, y), &c) in grid.iter() {
+                    lines[y as usize].push(c);
+                }
+            }
+            Frame::Colours(grid) => {
+                for ((x, y), [r, g, b]) in grid.iter() {
+                    let line = &mut lines[y as usize];
+                    *line += &format!("\x1b[48;2;{r};{g};{b}m  ");
+
+                    if x as usize == grid.width() - 1 {
+                        *line += "\x1b[0m";
+                    }
+                }
+            }
+        }
+
+        lines
+    }
+}
+
+/// The colour used for a character when a text frame is written as an image.
+pub fn char_colour(c: char) -> Rgb {
+    match c {
+        ' ' | '.' => [16, 16, 32],
+        '#' => [190, 190, 200],
+        'o' | 'O' => [230, 190, 80],
+        '@' => [230, 60, 60],
+        c => {
+            let hash = (c as u32).wrapping_mul(2654435761).to_be_bytes();
+            [hash[0] | 0x40, hash[1] | 0x40, hash[2] | 0x40]
+        }
+    }
+}
+
+/// Scales `frame` up so that every cell becomes `scale`×`scale` pixels and
+/// returns the pixels row by row.
+fn pixels(frame: &Frame, scale: usize) -> (usize, usize, Vec<Rgb>) {
+    let colours = frame.to_colours();
+    let (width, height) = (colours.width() * scale, colours.height() * scale);
+    let mut pixels = Vec::with_capacity(width * height);
+
+    for y in 0..height {
+        for x in 0..width {
+            pixels.push(colours[((x / scale) as i64, (y / scale) as i64)]);
+        }
+    }
+
+    (width, height, pixels)
+}
+
+/// Where recorded frames go.
+pub trait Sink {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()>;
+
+    /// Writes everything that was buffered.
+    fn finish(self: Box<Self>) -> io::Result<()>;
+}
+
+/// Writes every frame as a numbered binary PPM file into a directory.
+pub struct PpmSequence {
+    dir: PathBuf,
+    scale: usize,
+    count: usize,
+}
+
+impl PpmSequence {
+    pub fn new(dir: &Path, scale: usize) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+
+        Ok(PpmSequence {
+            dir: dir.to_owned(),
+            scale,
+            count: 0,
+        })
+    }
+}
+
+impl Sink for PpmSequence {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let path = self.dir.join(format!("frame{:05}.ppm", self.count));
+        let mut out = BufWriter::new(File::create(path)?);
+        let (width, height, pixels) = pixels(frame, self.scale);
+
+        write!(out, "P6\n{width} {height}\n255\n")?;
+        out.write_all(&pixels.concat())?;
+        self.count += 1;
+
+        out.flush()
+    }
+
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Writes all frames into one looping animated GIF.
+///
+/// The frames are compressed right away but only written at the end, when
+/// the size of the largest frame is known.
+pub struct Gif {
+    path: PathBuf,
+    scale: usize,
+    frames: Vec<gif::Frame<'static>>,
+    width: u16,
+    height: u16,
+}
+
+impl Gif {
+    pub fn new(path: &Path, scale: usize) -> Self {
+        Gif {
+            path: path.to_owned(),
+            scale,
+            frames: vec![],
+            width: 0,
+            height: 0,
+        }
+    }
+}
+
+/// Builds a palette for `pixels` and maps them to it. Frames with more than
+/// 256 colours fall back to 3 bits of red and green and 2 bits of blue.
+fn palette(pixels: &[Rgb]) -> (Vec<u8>, Vec<u8>) {
+    let mut colours = AHashMap::new();
+    let mut palette = vec![];
+    let mut index = Vec::with_capacity(pixels.len());
+
+    for &pixel in pixels {
+        let next = colours.len();
+
+        if next == 256 && !colours.contains_key(&pixel) {
+            let index = pixels
+                .iter()
+                .map(|&[r, g, b]| (r & 0xE0) | ((g & 0xE0) >> 3) | (b >> 6))
+                .collect();
+            let palette = (0..=255u8)
+                .flat_map(|i| [i & 0xE0, (i << 3) & 0xE0, (i << 6) & 0xC0])
+                .collect();
+
+            return (palette, index);
+        }
+
+        index.push(*colours.entry(pixel).or_insert_with(|| {
+            palette.extend(pixel);
+            next as u8
+        }));
+    }
+
+    (palette, index)
+}
+
+impl Sink for Gif {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let (width, height, pixels) = pixels(frame, self.scale);
+        let too_large = || {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frame too large for GIF",
+            )
+        };
+        let width = u16::try_from(width).map_err(|_| too_large())?;
+        let height = u16::try_from(height).map_err(|_| too_large())?;
+        let (palette, index) = palette(&pixels);
+
+        let mut frame = gif::Frame::from_palette_pixels(
+            width, height, index, palette, None,
+        );
+        frame.delay = FRAME_DELAY_MS / 10;
+        frame.dispose = gif::DisposalMethod::Background;
+        frame.make_lzw_pre_encoded();
+
+        self.width = self.width.max(width);
+        self.height = self.height.max(height);
+        self.frames.push(frame);
+
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        let out = BufWriter::new(File::create(&self.path)?);
+        let mut encoder = gif::Encoder::new(out, self.width, self.height, &[])
+            .map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        for frame in &self.frames {
+            encoder
+                .write_lzw_pre_encoded_frame(frame)
+                .map_err(io::Error::other)?;
+        }
+
+        encoder.into_inner()?.flush()
+    }
+}
+
+/// Writes all frames as an asciicast v2 recording, which can be played back
+/// in a terminal with `asciinema play`.
+pub struct Asciicast {
+    path: PathBuf,
+    events: Vec<String>,
+    width: usize,
+    height: usize,
+}
+
+impl Asciicast {
+    pub fn new(path: &Path) -> Self {
+        Asciicast {
+            path: path.to_owned(),
+            events: vec![],
+            width: 0,
+            height: 0,
+        }
+    }
+}
+
+impl Sink for Asciicast {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let time =
+            self.events.len() as f64 * f64::from(FRAME_DELAY_MS) / 1000.0;
+        // Move home and clear the screen before drawing the new frame.
+        let output =
+            "\x1b[H\x1b[J".to_owned() + &frame.to_terminal().join("\r\n");
+        let cell_width = match frame {
+            Frame::Text(_) => 1,
+            Frame::Colours(_) => 2,
+        };
+
+        self.width = self.width.max(frame.width() * cell_width);
+        self.height = self.height.max(frame.height());
+        self.events
+            .push(serde_json::json!([time, "o", output]).to_string());
+
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(&self.path)?);
+        let header = serde_json::json!({
+            "version": 2,
+            "width": self.width,
+            "height": self.height,
+        });
+
+        writeln!(out, "{header}")?;
+        for event in &self.events {
+            writeln!(out, "{event}")?;
+        }
+
+        out.flush()
+    }
+}
+
+/// Creates the sink matching the extension of `path`: `.gif` for an
+/// animated GIF, `.cast` for asciicast and a directory of PPM files for
+/// anything else.
+pub fn sink_for(path: &Path, scale: usize) -> io::Result<Box<dyn Sink>> {
+    Ok(match path.extension().and_then(|e| e.to_str()) {
+        Some("gif") => Box::new(Gif::new(path, scale)),
+        Some("cast") => Box::new(Asciicast::new(path)),
+        _ => Box::new(PpmSequence::new(path, scale)?),
+    })
+}
+
+/// Collects the frames of a simulation. Only every `skip + 1`th frame is
+/// kept, and frames are only built if they are kept, so a recorder that is
+/// [`off`](Recorder::off) costs next to nothing.
+pub struct Recorder {
+    sink: Option<Box<dyn Sink>>,
+    skip: usize,
+    offered: usize,
+    written: usize,
+    error: Option<io::Error>,
+}
+
+impl Recorder {
+    pub fn new(sink: Box<dyn Sink>, skip: usize) -> Self {
+        Recorder {
+            sink: Some(sink),
+            skip,
+            offered: 0,
+            written: 0,
+            error: None,
+        }
+    }
+
+    pub fn off() -> Self {
+        Recorder {
+            sink: None,
+            skip: 0,
+            offered: 0,
+            written: 0,
+            error: None,
+        }
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.sink.is_some()
+    }
+
+    /// Offers the next frame. `frame` is only called if the frame is kept.
+    pub fn frame(&mut self, frame: impl FnOnce() -> Frame) {
+        let Some(sink) = &mut self.sink else {
+            return;
+        };
+
+        self.offered += 1;
+
+        if !(self.offered - 1).is_multiple_of(self.skip + 1)
+            || self.error.is_some()
+        {
+            return;
+        }
+
+        match sink.frame(&frame()) {
+            Ok(()) => self.written += 1,
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    /// Finishes the output and returns the number of frames written.
+    pub fn finish(self) -> io::Result<usize> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+
+        if let Some(sink) = self.sink {
+            sink.finish()?;
+        }
+
+        Ok(self.written)
+    }
+}
+
+#[test]
+fn test_visual() {
+    use std::{cell::RefCell, rc::Rc};
+
+    struct Collect(Rc<RefCell<Vec<Frame>>>);
+
+    impl Sink for Collect {
+        fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+            self.0.borrow_mut().push(frame.clone());
+            Ok(())
+        }
+
+        fn finish(self: Box<Self>) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let frame = Frame::text("#.\n@");
+    assert_eq!((frame.width(), frame.height()), (2, 2));
+    assert_eq!(frame.to_terminal(), ["#.", "@ "]);
+    assert_eq!(frame.to_colours()[(0, 1)], char_colour('@'));
+
+    let (width, height, pixels) = pixels(&frame, 3);
+    assert_eq!((width, height, pixels.len()), (6, 6, 36));
+    assert_eq!(pixels[6 * 5 + 2], char_colour('@'));
+
+    let (palette, index) = palette(&pixels);
+    assert_eq!((palette.len(), index.len()), (9, 36));
+
+    let frames: Rc<RefCell<Vec<Frame>>> = Rc::default();
+    let mut recorder = Recorder::new(Box::new(Collect(Rc::clone(&frames))), 2);
+    let mut built = 0;
+
+    for i in 0..7 {
+        recorder.frame(|| {
+            built += 1;
+            Frame::text(&i.to_string())
+        });
+    }
+
+    assert_eq!(recorder.finish().unwrap(), 3);
+    assert_eq!(built, 3);
+    assert_eq!(frames.borrow()[2], Frame::text("6"));
+
+    let mut recorder = Recorder::off();
+    recorder.frame(|| unreachable!());
+    assert_eq!(recorder.finish().unwrap(), 0);
+}